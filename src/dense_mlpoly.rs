@@ -3,12 +3,11 @@ use super::errors::ProofVerifyError;
 use super::math::Math;
 use super::random::RandomTape;
 use super::scalar::Scalar;
-use super::transcript::{AppendToTranscript, ProofTranscript};
+use super::transcript::{AppendToTranscript, LigeroTranscript, MerlinTranscript, SpartanTranscript};
 use blake3::traits::digest;
 use core::ops::Index;
 use digest::Output;
 use ff::Field;
-use merlin::Transcript;
 use ligero_pc::{LigeroCommit, LigeroEncoding, LigeroEvalProof};
 use lcpc2d::{LcRoot};
 use serde::{Serialize, Deserialize};
@@ -25,6 +24,10 @@ pub struct DensePolynomial {
   Z: Vec<Scalar>, // evaluations of the polynomial in all the 2^num_vars Boolean inputs
 }
 
+/// Public parameters for the Ligero-backed polynomial commitment. This is
+/// the `Gens` type of [`super::poly_commit_scheme::LigeroPolyCommitmentScheme`];
+/// reach for that trait instead of this struct directly if the call site
+/// should be agnostic to the commitment backend.
 pub struct PolyCommitmentGens {
   pub gens: usize,
 }
@@ -38,10 +41,6 @@ impl PolyCommitmentGens {
   }
 }
 
-pub struct PolyCommitmentBlinds {
-  blinds: Vec<Scalar>,
-}
-
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PolyCommitment {
   C: LcRoot<Hasher, LigeroEncoding<Scalar>>,
@@ -51,6 +50,10 @@ pub struct PolyCommitment {
 pub struct PolyDecommitment {
   decomm: LigeroCommit<Hasher, Scalar>,
   enc: LigeroEncoding<Scalar>,
+  /// The extra row of uniformly random scalars appended to the committed
+  /// matrix when `DensePolynomial::commit` was given a `RandomTape`. `None`
+  /// for a non-hiding commitment.
+  blind_row: Option<Vec<Scalar>>,
 }
 
 pub struct EqPolynomial {
@@ -149,8 +152,8 @@ impl DensePolynomial {
 
   pub fn commit(
     &self,
-    _gens: &PolyCommitmentGens,
-    _random_tape: Option<&mut RandomTape>,
+    gens: &PolyCommitmentGens,
+    random_tape: Option<&mut RandomTape<MerlinTranscript>>,
   ) -> (PolyCommitment, PolyDecommitment) {
     let n = self.Z.len();
     let ell = self.get_num_vars();
@@ -159,9 +162,79 @@ impl DensePolynomial {
     //let enc = LigeroEncoding::new(coeffs.len());
     //let decomm = LigeroCommit::<Hasher, _>::commit(&coeffs, &enc).unwrap();
     let enc = LigeroEncoding::new_ml(self.num_vars);
-    let decomm = LigeroCommit::<Hasher, _>::commit(&self.Z, &enc).unwrap();
+
+    // In hiding mode, sample one extra row of fresh scalars from the random
+    // tape and append it to the matrix before Ligero encoding. `gens.gens`
+    // is the row width (n_per_row) the encoding already uses for `self`, so
+    // the appended row slots in as just another row of the same width.
+    // `PolyEvalProof::prove` folds this row into the combined-row opening
+    // with a fixed weight of one, masking what would otherwise be revealed
+    // in the clear; the returned evaluation commitment (`Zr + row_blind`)
+    // can only be unmasked by someone who knows this row.
+    let (data, blind_row) = match random_tape {
+      Some(tape) => {
+        let blind_row = tape.random_vector(b"ligero_hiding_blind_row", gens.gens);
+        let mut data = self.Z.clone();
+        data.extend_from_slice(&blind_row);
+        (data, Some(blind_row))
+      }
+      None => (self.Z.clone(), None),
+    };
+
+    let decomm = LigeroCommit::<Hasher, _>::commit(&data, &enc).unwrap();
     let C = decomm.get_root(); // this is the polynomial commitment
-    (PolyCommitment { C }, PolyDecommitment { decomm, enc })
+    (
+      PolyCommitment { C },
+      PolyDecommitment {
+        decomm,
+        enc,
+        blind_row,
+      },
+    )
+  }
+
+  /// Commits several same-shaped polynomials as row blocks of a single
+  /// Ligero matrix (padding the block count to a power of two with
+  /// zero-polynomials), so [`BatchedPolyEvalProof`] can open all of them at
+  /// a shared point while authenticating each sampled column only once.
+  pub fn commit_batch(
+    polys: &[&DensePolynomial],
+    gens: &PolyCommitmentGens,
+    random_tape: Option<&mut RandomTape<MerlinTranscript>>,
+  ) -> (PolyCommitment, PolyDecommitment) {
+    assert!(!polys.is_empty());
+    let num_vars = polys[0].get_num_vars();
+    for poly in polys {
+      assert_eq!(poly.get_num_vars(), num_vars);
+    }
+
+    // reuse the same per-row encoding a lone polynomial of this shape would
+    // get; stacking more (possibly blinding) rows on top doesn't change the
+    // row width, only the row count the commitment ends up covering.
+    let enc = LigeroEncoding::new_ml(num_vars);
+
+    let k_padded = polys.len().next_power_of_two();
+    let mut data: Vec<Scalar> = Vec::with_capacity(k_padded * num_vars.pow2());
+    for poly in polys {
+      data.extend_from_slice(&poly.Z);
+    }
+    data.resize(k_padded * num_vars.pow2(), Scalar::zero());
+
+    let blind_row = random_tape.map(|tape| tape.random_vector(b"ligero_hiding_blind_row", gens.gens));
+    if let Some(blind_row) = &blind_row {
+      data.extend_from_slice(blind_row);
+    }
+
+    let decomm = LigeroCommit::<Hasher, _>::commit(&data, &enc).unwrap();
+    let C = decomm.get_root();
+    (
+      PolyCommitment { C },
+      PolyDecommitment {
+        decomm,
+        enc,
+        blind_row,
+      },
+    )
   }
 
   pub fn bound_poly_var_top(&mut self, r: &Scalar) {
@@ -195,6 +268,14 @@ impl DensePolynomial {
     &self.Z
   }
 
+  /// Exposes the raw evaluation table, for commitment backends (e.g.
+  /// [`super::poly_commit_scheme::mlkzg`]) that need to hand it to a third-party
+  /// multilinear-polynomial representation rather than go through
+  /// [`DensePolynomial::commit`].
+  pub fn evals_ref(&self) -> &[Scalar] {
+    &self.Z
+  }
+
   pub fn extend(&mut self, other: &DensePolynomial) {
     // TODO: allow extension even when some vars are bound
     assert_eq!(self.Z.len(), self.len);
@@ -240,7 +321,7 @@ impl Index<usize> for DensePolynomial {
 }
 
 impl AppendToTranscript for PolyCommitment {
-  fn append_to_transcript(&self, label: &'static [u8], transcript: &mut Transcript) {
+  fn append_to_transcript<T: SpartanTranscript>(&self, label: &'static [u8], transcript: &mut T) {
     transcript.append_message(label, b"poly_commitment_begin");
     transcript.append_message(b"poly_commitment_share", &self.C.as_ref());
     transcript.append_message(label, b"poly_commitment_end");
@@ -252,6 +333,11 @@ pub struct PolyEvalProof {
   proof: LigeroEvalProof<Hasher, Scalar>,
   left_num_vars: usize,
   right_num_vars: usize,
+  /// Whether the commitment this proof opens carries the extra blinding row
+  /// from [`DensePolynomial::commit`]; if so, `verify` expects the caller to
+  /// pass the evaluation commitment (`Zr + row_blind`) `prove` returned,
+  /// rather than `Zr` itself.
+  hiding: bool,
 }
 
 impl PolyEvalProof {
@@ -259,50 +345,61 @@ impl PolyEvalProof {
     b"polynomial evaluation proof"
   }
 
-  pub fn prove(
+  // Generic over any `SpartanTranscript` backend, but bounded by
+  // `LigeroTranscript` because the column-opening call below hands the
+  // transcript straight to the vendored `ligero_pc` crate, which is written
+  // against `merlin::Transcript`; see `LigeroTranscript`'s doc comment. Since
+  // `MerlinTranscript` is the only type implementing `LigeroTranscript`,
+  // evaluation proofs over Ligero commitments are not actually reachable
+  // from the Poseidon backend today, even though this signature is generic
+  // — driving them from `poseidon::PoseidonTranscript` would need a
+  // column-opening protocol that doesn't hand off to a byte-oriented
+  // `merlin::Transcript`, which is out of scope here. `SumcheckInstanceProof`
+  // and `ProductCircuitEvalProof` (in [`super::sumcheck`]/
+  // [`super::product_tree`]) carry no such bound and do run end to end over
+  // Poseidon; `prove_mem_check`/`SparseMatPolyEvalProof` (in
+  // [`super::sparse_mlpoly`]) still require `LigeroTranscript` because they
+  // bottom out in exactly this function's openings.
+  pub fn prove<T: SpartanTranscript + LigeroTranscript>(
     poly: &DensePolynomial,
     decomm: &PolyDecommitment,
-    blinds_opt: Option<&PolyCommitmentBlinds>,
-    r: &[Scalar],                  // point at which the polynomial is evaluated
-    _Zr: &Scalar,                  // evaluation of \widetilde{Z}(r)
-    blind_Zr_opt: Option<&Scalar>, // specifies a blind for Zr
-    _gens: &PolyCommitmentGens,
-    transcript: &mut Transcript,
-    _random_tape: &mut RandomTape,
-  ) -> PolyEvalProof {
+    r: &[Scalar], // point at which the polynomial is evaluated
+    Zr: &Scalar,  // evaluation of \widetilde{Z}(r)
+    gens: &PolyCommitmentGens,
+    transcript: &mut T,
+  ) -> (PolyEvalProof, Scalar) {
     transcript.append_protocol_name(PolyEvalProof::protocol_name());
 
     // assert vectors are of the right size
     assert_eq!(poly.get_num_vars(), r.len());
 
-    // compute L and R
-    let (left_num_vars, right_num_vars) = (
-      decomm.decomm.get_n_rows().log2(),
-      r.len() - decomm.decomm.get_n_rows().log2(),
-    );
+    // `get_n_per_row` (the row width) is fixed by `gens` regardless of
+    // whether a blinding row was appended at commit time, unlike
+    // `get_n_rows`, which is one larger in hiding mode; derive the L/R split
+    // from the former so it stays correct in both modes.
+    let right_num_vars = decomm.decomm.get_n_per_row().log2();
+    let left_num_vars = r.len() - right_num_vars;
     let L_size = left_num_vars.pow2();
     let R_size = right_num_vars.pow2();
 
-    let default_blinds = PolyCommitmentBlinds {
-      blinds: vec![Scalar::zero(); L_size],
-    };
-    let blinds = blinds_opt.map_or(&default_blinds, |p| p);
-
-    assert_eq!(blinds.blinds.len(), L_size);
-
-    let zero = Scalar::zero();
-    let _blind_Zr = blind_Zr_opt.map_or(&zero, |p| p);
-
     // compute the L and R vectors
     let L = EqPolynomial::new(r[..left_num_vars].to_vec()).evals();
     let R = EqPolynomial::new(r[left_num_vars..].to_vec()).evals();
     assert_eq!(L.len(), L_size);
     assert_eq!(R.len(), R_size);
 
-    assert_eq!(decomm.decomm.get_n_rows(), L.len());
+    // When hiding, fold the blinding row into the row combination with a
+    // fixed weight of one, so the row the Ligero IOP reveals is masked by a
+    // one-time pad rather than being `L^T . Z` in the clear.
+    let hiding = decomm.blind_row.is_some();
+    let mut L_full = L.clone();
+    if hiding {
+      L_full.push(Scalar::one());
+    }
+    assert_eq!(decomm.decomm.get_n_rows(), L_full.len());
 
     // L is the outer tensor.  R is the inner tensor.
-    let proof = decomm.decomm.prove(&L, &decomm.enc, transcript);
+    let proof = decomm.decomm.prove(&L_full, &decomm.enc, transcript.as_merlin_mut());
 
     if proof.is_err() {
       println!("{:?}", proof);
@@ -311,26 +408,41 @@ impl PolyEvalProof {
     let proof = proof.unwrap();
 
     assert_eq!(decomm.decomm.get_n_per_row(), proof.get_n_per_row());
-    assert_eq!(
-      decomm.decomm.get_n_per_row() * decomm.decomm.get_n_rows(),
-      1 << r.len()
-    );
-
     assert_eq!(R.len(), decomm.decomm.get_n_per_row());
 
-    PolyEvalProof {
-      proof,
-      left_num_vars,
-      right_num_vars,
-    }
+    // The evaluation commitment: Zr masked by R . blind_row (the
+    // contribution the blinding row adds to the inner product with R). A
+    // verifier without the blind row learns nothing about Zr from this
+    // scalar alone.
+    let eval_commit = if hiding {
+      let blind_row = decomm.blind_row.as_ref().unwrap();
+      let row_blind: Scalar = R.iter().zip(blind_row.iter()).map(|(r_i, b_i)| *r_i * *b_i).sum();
+      *Zr + row_blind
+    } else {
+      *Zr
+    };
+
+    (
+      PolyEvalProof {
+        proof,
+        left_num_vars,
+        right_num_vars,
+        hiding,
+      },
+      eval_commit,
+    )
   }
 
-  pub fn verify(
+  /// Verifies an evaluation proof. `eval` must be `Zr` for a non-hiding
+  /// proof, or the evaluation commitment returned alongside the proof by
+  /// `prove` (`Zr + blind`) for a hiding one; `verify_plain` is the shortcut
+  /// for callers that already know the proof is non-hiding.
+  pub fn verify<T: SpartanTranscript + LigeroTranscript>(
     &self,
     _gens: &PolyCommitmentGens,
-    transcript: &mut Transcript,
+    transcript: &mut T,
     r: &[Scalar],  // point at which the polynomial is evaluated
-    eval: &Scalar, // commitment to \widetilde{Z}(r)
+    eval: &Scalar, // Zr, or its evaluation commitment in the hiding path
     comm: &PolyCommitment,
   ) -> Result<(), ProofVerifyError> {
     transcript.append_protocol_name(PolyEvalProof::protocol_name());
@@ -346,10 +458,16 @@ impl PolyEvalProof {
     assert_eq!(L.len(), L_size);
     assert_eq!(R.len(), R_size);
     assert_eq!(R.len(), self.proof.get_n_per_row());
+
+    let mut L_full = L;
+    if self.hiding {
+      L_full.push(Scalar::one());
+    }
+
     let enc = LigeroEncoding::new_from_dims(self.proof.get_n_per_row(), self.proof.get_n_cols());
     let res = self
       .proof
-      .verify(&comm.C.clone().into_raw(), &L, &R, &enc, transcript)
+      .verify(&comm.C.clone().into_raw(), &L_full, &R, &enc, transcript.as_merlin_mut())
       .unwrap();
 
     if res == *eval {
@@ -359,18 +477,307 @@ impl PolyEvalProof {
     }
   }
 
-  pub fn verify_plain(
+  pub fn verify_plain<T: SpartanTranscript + LigeroTranscript>(
     &self,
     gens: &PolyCommitmentGens,
-    transcript: &mut Transcript,
+    transcript: &mut T,
     r: &[Scalar], // point at which the polynomial is evaluated
     Zr: &Scalar,  // evaluation \widetilde{Z}(r)
     comm: &PolyCommitment,
   ) -> Result<(), ProofVerifyError> {
+    debug_assert!(!self.hiding, "use verify with the evaluation commitment for a hiding proof");
     self.verify(gens, transcript, r, &Zr, comm)
   }
 }
 
+/// An evaluation proof for several same-shaped polynomials at one shared
+/// point, amortized over a single Ligero commitment (see
+/// [`DensePolynomial::commit_batch`]). Instead of one column-authentication
+/// path per polynomial, the verifier's random columns are sampled once
+/// against the joint matrix and reused for every polynomial in the batch.
+///
+/// The polynomials are combined as `g = \sum_i \rho^i f_i` for a
+/// transcript-derived `\rho`, following halo2's multiopen technique; proving
+/// `g(r)` then takes a single row-combination over the joint matrix, as a
+/// single-polynomial proof does over its own; the claimed evaluation is the same random linear
+/// combination of the individual evaluations. For the transposed case —
+/// one polynomial opened at several points — see
+/// [`BatchedPolyEvalProofMultiPoint`], which combines claimed evaluations
+/// the same way but can't share a single row-combination proof across
+/// points, since each point's `L_j` differs.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchedPolyEvalProof {
+  proof: LigeroEvalProof<Hasher, Scalar>,
+  left_num_vars: usize,  // of a single polynomial in the batch
+  right_num_vars: usize, // of a single polynomial in the batch
+  num_polys: usize,
+  hiding: bool,
+}
+
+impl BatchedPolyEvalProof {
+  fn protocol_name() -> &'static [u8] {
+    b"batched polynomial evaluation proof"
+  }
+
+  /// Proves that `evals[i] == polys[i].evaluate(r)` for every `i`, given the
+  /// joint commitment `comm`/`decomm` returned by `DensePolynomial::commit_batch`.
+  /// Returns the proof and the random linear combination of `evals` the
+  /// verifier should check it against.
+  pub fn prove<T: SpartanTranscript + LigeroTranscript>(
+    polys: &[&DensePolynomial],
+    comm: &PolyCommitment,
+    decomm: &PolyDecommitment,
+    r: &[Scalar],
+    evals: &[Scalar],
+    transcript: &mut T,
+  ) -> (BatchedPolyEvalProof, Scalar) {
+    transcript.append_protocol_name(BatchedPolyEvalProof::protocol_name());
+    assert_eq!(polys.len(), evals.len());
+    assert!(!polys.is_empty());
+    assert_eq!(polys[0].get_num_vars(), r.len());
+
+    let right_num_vars = decomm.decomm.get_n_per_row().log2();
+    let left_num_vars = r.len() - right_num_vars;
+    let L = EqPolynomial::new(r[..left_num_vars].to_vec()).evals();
+    let R = EqPolynomial::new(r[left_num_vars..].to_vec()).evals();
+    assert_eq!(L.len(), left_num_vars.pow2());
+    assert_eq!(R.len(), right_num_vars.pow2());
+
+    // rho must be bound to the joint commitment, or a prover could pick the
+    // per-poly evals (and a matching combined_eval) independently of what's
+    // actually committed; absorb it here rather than trust the caller to
+    // have done so earlier.
+    comm.append_to_transcript(b"batch_comm", transcript);
+    let rho: Scalar = transcript.challenge_scalar(b"batch_rho");
+    let k_padded = polys.len().next_power_of_two();
+    let mut rho_pows = vec![Scalar::one(); k_padded];
+    for i in 1..k_padded {
+      rho_pows[i] = rho_pows[i - 1] * rho;
+    }
+
+    // tile L across the k_padded row-blocks of the joint matrix, scaling
+    // block i by rho^i; blocks beyond polys.len() are the zero-polynomial
+    // padding `commit_batch` added, so their weight doesn't matter.
+    let mut L_tiled = Vec::with_capacity(L.len() * k_padded);
+    for rho_i in &rho_pows {
+      for l in &L {
+        L_tiled.push(*rho_i * *l);
+      }
+    }
+
+    let hiding = decomm.blind_row.is_some();
+    if hiding {
+      L_tiled.push(Scalar::one());
+    }
+    assert_eq!(decomm.decomm.get_n_rows(), L_tiled.len());
+
+    let proof = decomm
+      .decomm
+      .prove(&L_tiled, &decomm.enc, transcript.as_merlin_mut())
+      .unwrap();
+    assert_eq!(R.len(), decomm.decomm.get_n_per_row());
+
+    let combined_eval: Scalar = evals
+      .iter()
+      .zip(rho_pows.iter())
+      .map(|(e, rho_i)| *e * *rho_i)
+      .sum();
+
+    (
+      BatchedPolyEvalProof {
+        proof,
+        left_num_vars,
+        right_num_vars,
+        num_polys: polys.len(),
+        hiding,
+      },
+      combined_eval,
+    )
+  }
+
+  /// Verifies a batched proof against the joint commitment. `evals` is the
+  /// verifier's own per-polynomial claimed evaluations (not a
+  /// prover-supplied combination): this recombines them with the same rho
+  /// powers `prove` used, so the proof actually binds every `evals[i]` to
+  /// `polys[i].evaluate(r)` rather than only to whatever scalar the prover
+  /// hands over.
+  pub fn verify<T: SpartanTranscript + LigeroTranscript>(
+    &self,
+    transcript: &mut T,
+    r: &[Scalar],
+    evals: &[Scalar],
+    comm: &PolyCommitment,
+  ) -> Result<(), ProofVerifyError> {
+    transcript.append_protocol_name(BatchedPolyEvalProof::protocol_name());
+    assert_eq!(evals.len(), self.num_polys);
+
+    let (left_num_vars, right_num_vars) = (self.left_num_vars, self.right_num_vars);
+    assert_eq!(left_num_vars + right_num_vars, r.len());
+    let L = EqPolynomial::new(r[..left_num_vars].to_vec()).evals();
+    let R = EqPolynomial::new(r[left_num_vars..].to_vec()).evals();
+    assert_eq!(R.len(), self.proof.get_n_per_row());
+
+    comm.append_to_transcript(b"batch_comm", transcript);
+    let rho: Scalar = transcript.challenge_scalar(b"batch_rho");
+    let k_padded = self.num_polys.next_power_of_two();
+    let mut rho_pows = vec![Scalar::one(); k_padded];
+    for i in 1..k_padded {
+      rho_pows[i] = rho_pows[i - 1] * rho;
+    }
+
+    let mut L_tiled = Vec::with_capacity(L.len() * k_padded);
+    for rho_i in &rho_pows {
+      for l in &L {
+        L_tiled.push(*rho_i * *l);
+      }
+    }
+    if self.hiding {
+      L_tiled.push(Scalar::one());
+    }
+
+    let enc = LigeroEncoding::new_from_dims(self.proof.get_n_per_row(), self.proof.get_n_cols());
+    let res = self
+      .proof
+      .verify(&comm.C.clone().into_raw(), &L_tiled, &R, &enc, transcript.as_merlin_mut())
+      .unwrap();
+
+    let combined_eval: Scalar = evals.iter().zip(rho_pows.iter()).map(|(e, rho_i)| *e * *rho_i).sum();
+
+    if res == combined_eval {
+      Ok(())
+    } else {
+      Err(ProofVerifyError::InternalError)
+    }
+  }
+}
+
+/// An evaluation proof for one polynomial opened at several points against
+/// its existing single-polynomial commitment (see [`DensePolynomial::commit`]).
+/// Unlike [`BatchedPolyEvalProof`], the points don't share an `L`/`R` split
+/// (each `r_j` tensors into its own `L_j \otimes R_j`), so the column
+/// authentication itself can't be shared across points; what's still
+/// amortized is the Fiat-Shamir flow and the verifier's final check, which
+/// combines every `f(r_j)` into a single equation instead of `j` separate
+/// ones: `g = \sum_j \rho^j f(r_j)` for a transcript-derived `\rho`, the same
+/// halo2-style combination `BatchedPolyEvalProof` uses across polynomials.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchedPolyEvalProofMultiPoint {
+  proofs: Vec<LigeroEvalProof<Hasher, Scalar>>,
+  left_num_vars: usize,
+  right_num_vars: usize,
+  hiding: bool,
+}
+
+impl BatchedPolyEvalProofMultiPoint {
+  fn protocol_name() -> &'static [u8] {
+    b"batched polynomial evaluation proof, multi-point"
+  }
+
+  /// Proves that `evals[j] == poly.evaluate(&points[j])` for every `j`,
+  /// given the commitment `comm`/`decomm` returned by `DensePolynomial::commit`.
+  /// Returns the proof and the random linear combination of `evals` the
+  /// verifier should check it against.
+  pub fn prove<T: SpartanTranscript + LigeroTranscript>(
+    poly: &DensePolynomial,
+    comm: &PolyCommitment,
+    decomm: &PolyDecommitment,
+    points: &[Vec<Scalar>],
+    evals: &[Scalar],
+    transcript: &mut T,
+  ) -> (BatchedPolyEvalProofMultiPoint, Scalar) {
+    transcript.append_protocol_name(BatchedPolyEvalProofMultiPoint::protocol_name());
+    assert_eq!(points.len(), evals.len());
+    assert!(!points.is_empty());
+    for r in points {
+      assert_eq!(poly.get_num_vars(), r.len());
+    }
+
+    let right_num_vars = decomm.decomm.get_n_per_row().log2();
+    let left_num_vars = points[0].len() - right_num_vars;
+    let hiding = decomm.blind_row.is_some();
+
+    comm.append_to_transcript(b"batch_comm", transcript);
+    let rho: Scalar = transcript.challenge_scalar(b"batch_rho");
+    let mut rho_pows = vec![Scalar::one(); points.len()];
+    for i in 1..points.len() {
+      rho_pows[i] = rho_pows[i - 1] * rho;
+    }
+
+    let proofs = points
+      .iter()
+      .map(|r| {
+        let L = EqPolynomial::new(r[..left_num_vars].to_vec()).evals();
+        let mut L_full = L;
+        if hiding {
+          L_full.push(Scalar::one());
+        }
+        assert_eq!(decomm.decomm.get_n_rows(), L_full.len());
+        decomm.decomm.prove(&L_full, &decomm.enc, transcript.as_merlin_mut()).unwrap()
+      })
+      .collect();
+
+    let combined_eval: Scalar = evals.iter().zip(rho_pows.iter()).map(|(e, rho_i)| *e * *rho_i).sum();
+
+    (
+      BatchedPolyEvalProofMultiPoint {
+        proofs,
+        left_num_vars,
+        right_num_vars,
+        hiding,
+      },
+      combined_eval,
+    )
+  }
+
+  /// Verifies a multi-point proof against the polynomial's commitment.
+  /// `evals` is the verifier's own per-point claimed evaluations, recombined
+  /// here with the same rho powers `prove` used.
+  pub fn verify<T: SpartanTranscript + LigeroTranscript>(
+    &self,
+    transcript: &mut T,
+    points: &[Vec<Scalar>],
+    evals: &[Scalar],
+    comm: &PolyCommitment,
+  ) -> Result<(), ProofVerifyError> {
+    transcript.append_protocol_name(BatchedPolyEvalProofMultiPoint::protocol_name());
+    assert_eq!(points.len(), self.proofs.len());
+    assert_eq!(points.len(), evals.len());
+
+    let (left_num_vars, right_num_vars) = (self.left_num_vars, self.right_num_vars);
+
+    comm.append_to_transcript(b"batch_comm", transcript);
+    let rho: Scalar = transcript.challenge_scalar(b"batch_rho");
+    let mut rho_pows = vec![Scalar::one(); points.len()];
+    for i in 1..points.len() {
+      rho_pows[i] = rho_pows[i - 1] * rho;
+    }
+
+    let enc = LigeroEncoding::new_from_dims(self.proofs[0].get_n_per_row(), self.proofs[0].get_n_cols());
+    let mut combined_res = Scalar::zero();
+    for ((r, proof), rho_i) in points.iter().zip(self.proofs.iter()).zip(rho_pows.iter()) {
+      assert_eq!(left_num_vars + right_num_vars, r.len());
+      let L = EqPolynomial::new(r[..left_num_vars].to_vec()).evals();
+      let R = EqPolynomial::new(r[left_num_vars..].to_vec()).evals();
+      let mut L_full = L;
+      if self.hiding {
+        L_full.push(Scalar::one());
+      }
+      let res = proof
+        .verify(&comm.C.clone().into_raw(), &L_full, &R, &enc, transcript.as_merlin_mut())
+        .unwrap();
+      combined_res = combined_res + *rho_i * res;
+    }
+
+    let combined_eval: Scalar = evals.iter().zip(rho_pows.iter()).map(|(e, rho_i)| *e * *rho_i).sum();
+
+    if combined_res == combined_eval {
+      Ok(())
+    } else {
+      Err(ProofVerifyError::InternalError)
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::super::scalar::ScalarFromPrimitives;
@@ -554,21 +961,10 @@ mod tests {
     let gens = PolyCommitmentGens::new(poly.get_num_vars(), b"test-two");
     let (poly_comm, poly_decomm) = poly.commit(&gens, None);
 
-    let mut random_tape = RandomTape::new(b"proof");
-    let mut prover_transcript = Transcript::new(b"example");
-    let proof = PolyEvalProof::prove(
-      &poly,
-      &poly_decomm,
-      None,
-      &r,
-      &eval,
-      None,
-      &gens,
-      &mut prover_transcript,
-      &mut random_tape,
-    );
+    let mut prover_transcript = MerlinTranscript::new(b"example");
+    let (proof, _eval_commit) = PolyEvalProof::prove(&poly, &poly_decomm, &r, &eval, &gens, &mut prover_transcript);
 
-    let mut verifier_transcript = Transcript::new(b"example");
+    let mut verifier_transcript = MerlinTranscript::new(b"example");
     assert!(proof
       .verify(&gens, &mut verifier_transcript, &r, &eval, &poly_comm)
       .is_ok());
@@ -593,38 +989,130 @@ mod tests {
     let gens = PolyCommitmentGens::new(poly.get_num_vars(), b"test-two");
     let (poly_comm, poly_decomm) = poly.commit(&gens, None);
 
-    let mut random_tape = RandomTape::new(b"proof");
-    let mut prover_transcript = Transcript::new(b"example");
-    let proof = PolyEvalProof::prove(
-      &poly,
-      &poly_decomm,
-      None,
-      &r,
-      &eval,
-      None,
-      &gens,
-      &mut prover_transcript,
-      &mut random_tape,
-    );
+    let mut prover_transcript = MerlinTranscript::new(b"example");
+    let (proof, eval_commit) =
+      PolyEvalProof::prove(&poly, &poly_decomm, &r, &eval, &gens, &mut prover_transcript);
+
+    let (proof2, eval_commit2) =
+      PolyEvalProof::prove(&poly, &poly_decomm, &r, &eval, &gens, &mut prover_transcript);
+
+    let mut verifier_transcript = MerlinTranscript::new(b"example");
+    assert!(proof
+      .verify(&gens, &mut verifier_transcript, &r, &eval_commit, &poly_comm)
+      .is_ok());
+    assert!(proof2
+      .verify(&gens, &mut verifier_transcript, &r, &eval_commit2, &poly_comm)
+      .is_ok());
+  }
+
+  #[test]
+  fn check_polynomial_commit_hiding() {
+    let mut Z: Vec<Scalar> = Vec::new();
+    for _i in 0..4096 {
+      Z.push((2 as usize).to_scalar());
+    }
+    let poly = DensePolynomial::new(Z);
+
+    let mut r: Vec<Scalar> = Vec::new();
+    for _i in 0..12 {
+      r.push((4 as usize).to_scalar());
+    }
+    let eval = poly.evaluate(&r);
+
+    let gens = PolyCommitmentGens::new(poly.get_num_vars(), b"test-hiding");
+    let mut commit_tape = RandomTape::<MerlinTranscript>::new(b"commit");
+    let (poly_comm, poly_decomm) = poly.commit(&gens, Some(&mut commit_tape));
+
+    let mut prover_transcript = MerlinTranscript::new(b"example");
+    let (proof, eval_commit) =
+      PolyEvalProof::prove(&poly, &poly_decomm, &r, &eval, &gens, &mut prover_transcript);
+
+    // the evaluation commitment hides Zr: it isn't just Zr itself.
+    assert_ne!(eval_commit, eval);
+
+    let mut verifier_transcript = MerlinTranscript::new(b"example");
+    assert!(proof
+      .verify(&gens, &mut verifier_transcript, &r, &eval_commit, &poly_comm)
+      .is_ok());
+  }
+
+  #[test]
+  fn check_polynomial_commit_batched() {
+    let mut Z1: Vec<Scalar> = Vec::new();
+    let mut Z2: Vec<Scalar> = Vec::new();
+    for i in 0..4096 {
+      Z1.push((2 as usize).to_scalar());
+      Z2.push((i % 7).to_scalar());
+    }
+    let poly1 = DensePolynomial::new(Z1);
+    let poly2 = DensePolynomial::new(Z2);
+    let polys = [&poly1, &poly2];
+
+    let mut r: Vec<Scalar> = Vec::new();
+    for _i in 0..12 {
+      r.push((4 as usize).to_scalar());
+    }
+    let evals = [poly1.evaluate(&r), poly2.evaluate(&r)];
+
+    let gens = PolyCommitmentGens::new(poly1.get_num_vars(), b"test-batched");
+    let (joint_comm, joint_decomm) = DensePolynomial::commit_batch(&polys, &gens, None);
+
+    let mut prover_transcript = MerlinTranscript::new(b"example");
+    let (proof, _combined_eval) =
+      BatchedPolyEvalProof::prove(&polys, &joint_comm, &joint_decomm, &r, &evals, &mut prover_transcript);
+
+    let mut verifier_transcript = MerlinTranscript::new(b"example");
+    assert!(proof
+      .verify(&mut verifier_transcript, &r, &evals, &joint_comm)
+      .is_ok());
+
+    // the proof binds every individual eval, not just whatever combination
+    // the prover hands over: claiming a different eval for either poly must
+    // be rejected, even though some other combined value would match it.
+    let mut wrong_evals = evals;
+    wrong_evals[0] = wrong_evals[0] + Scalar::one();
+    let mut verifier_transcript = MerlinTranscript::new(b"example");
+    assert!(proof
+      .verify(&mut verifier_transcript, &r, &wrong_evals, &joint_comm)
+      .is_err());
+  }
+
+  #[test]
+  fn check_polynomial_commit_batched_multi_point() {
+    let mut Z: Vec<Scalar> = Vec::new();
+    for i in 0..4096 {
+      Z.push((i % 7).to_scalar());
+    }
+    let poly = DensePolynomial::new(Z);
+
+    let r1: Vec<Scalar> = (0..12).map(|_| (4 as usize).to_scalar()).collect();
+    let r2: Vec<Scalar> = (0..12).map(|_| (5 as usize).to_scalar()).collect();
+    let points = [r1, r2];
+    let evals: Vec<Scalar> = points.iter().map(|r| poly.evaluate(r)).collect();
+
+    let gens = PolyCommitmentGens::new(poly.get_num_vars(), b"test-batched-multi-point");
+    let (poly_comm, poly_decomm) = poly.commit(&gens, None);
 
-    let proof2 = PolyEvalProof::prove(
+    let mut prover_transcript = MerlinTranscript::new(b"example");
+    let (proof, _combined_eval) = BatchedPolyEvalProofMultiPoint::prove(
       &poly,
+      &poly_comm,
       &poly_decomm,
-      None,
-      &r,
-      &eval,
-      None,
-      &gens,
+      &points,
+      &evals,
       &mut prover_transcript,
-      &mut random_tape,
     );
 
-    let mut verifier_transcript = Transcript::new(b"example");
+    let mut verifier_transcript = MerlinTranscript::new(b"example");
     assert!(proof
-      .verify(&gens, &mut verifier_transcript, &r, &eval, &poly_comm)
-      .is_ok());
-    assert!(proof2
-      .verify(&gens, &mut verifier_transcript, &r, &eval, &poly_comm)
+      .verify(&mut verifier_transcript, &points, &evals, &poly_comm)
       .is_ok());
+
+    let mut wrong_evals = evals;
+    wrong_evals[1] = wrong_evals[1] + Scalar::one();
+    let mut verifier_transcript = MerlinTranscript::new(b"example");
+    assert!(proof
+      .verify(&mut verifier_transcript, &points, &wrong_evals, &poly_comm)
+      .is_err());
   }
 }
@@ -0,0 +1,241 @@
+#![allow(clippy::too_many_arguments)]
+use super::dense_mlpoly::{DensePolynomial, PolyCommitment, PolyCommitmentGens, PolyDecommitment, PolyEvalProof};
+use super::errors::ProofVerifyError;
+use super::random::RandomTape;
+use super::scalar::Scalar;
+use super::transcript::MerlinTranscript;
+
+/// A backend-agnostic polynomial-commitment scheme for multilinear polynomials.
+///
+/// `DensePolynomial::commit` and friends are hard-wired to the Ligero-based
+/// implementation below; this trait lets callers swap in a different
+/// commitment scheme (e.g. a pairing-based one) without touching the R1CS/
+/// sumcheck call sites, as long as the replacement implements the same
+/// setup/commit/prove/verify surface.
+pub trait PolyCommitmentScheme {
+  type Gens;
+  type Commitment;
+  type Decommitment;
+  type EvalProof;
+
+  /// Produces public parameters sized for a polynomial with `num_vars` variables.
+  fn setup(num_vars: usize, label: &'static [u8]) -> Self::Gens;
+
+  /// Commits to `poly`, optionally hiding it using entropy drawn from `random_tape`.
+  fn commit(
+    poly: &DensePolynomial,
+    gens: &Self::Gens,
+    random_tape: Option<&mut RandomTape<MerlinTranscript>>,
+  ) -> (Self::Commitment, Self::Decommitment);
+
+  /// Proves that the committed polynomial evaluates to `Zr` at `r`. Returns
+  /// the proof together with the value the verifier should treat as the
+  /// evaluation: `Zr` itself for a non-hiding commitment, or an evaluation
+  /// commitment masking `Zr` when `decomm` carries hiding state.
+  fn prove(
+    decomm: &Self::Decommitment,
+    poly: &DensePolynomial,
+    r: &[Scalar],
+    Zr: &Scalar,
+    gens: &Self::Gens,
+    transcript: &mut MerlinTranscript,
+    random_tape: &mut RandomTape<MerlinTranscript>,
+  ) -> (Self::EvalProof, Scalar);
+
+  /// Verifies an evaluation proof against a commitment. `eval` is whichever
+  /// value `prove` returned alongside the proof.
+  fn verify(
+    proof: &Self::EvalProof,
+    gens: &Self::Gens,
+    transcript: &mut MerlinTranscript,
+    r: &[Scalar],
+    eval: &Scalar,
+    comm: &Self::Commitment,
+  ) -> Result<(), ProofVerifyError>;
+}
+
+/// The default scheme: Ligero-encoded commitments over a Reed-Solomon code,
+/// transparent (no trusted setup) but with commitments/proofs linear in the
+/// square root of the polynomial size.
+pub struct LigeroPolyCommitmentScheme;
+
+impl PolyCommitmentScheme for LigeroPolyCommitmentScheme {
+  type Gens = PolyCommitmentGens;
+  type Commitment = PolyCommitment;
+  type Decommitment = PolyDecommitment;
+  type EvalProof = PolyEvalProof;
+
+  fn setup(num_vars: usize, label: &'static [u8]) -> Self::Gens {
+    PolyCommitmentGens::new(num_vars, label)
+  }
+
+  fn commit(
+    poly: &DensePolynomial,
+    gens: &Self::Gens,
+    random_tape: Option<&mut RandomTape<MerlinTranscript>>,
+  ) -> (Self::Commitment, Self::Decommitment) {
+    poly.commit(gens, random_tape)
+  }
+
+  fn prove(
+    decomm: &Self::Decommitment,
+    poly: &DensePolynomial,
+    r: &[Scalar],
+    Zr: &Scalar,
+    gens: &Self::Gens,
+    transcript: &mut MerlinTranscript,
+    _random_tape: &mut RandomTape<MerlinTranscript>,
+  ) -> (Self::EvalProof, Scalar) {
+    PolyEvalProof::prove(poly, decomm, r, Zr, gens, transcript)
+  }
+
+  fn verify(
+    proof: &Self::EvalProof,
+    gens: &Self::Gens,
+    transcript: &mut MerlinTranscript,
+    r: &[Scalar],
+    eval: &Scalar,
+    comm: &Self::Commitment,
+  ) -> Result<(), ProofVerifyError> {
+    proof.verify(gens, transcript, r, eval, comm)
+  }
+}
+
+/// The scheme this crate treats as its default. No entry point selects a
+/// backend through this alias: `PolyCommitmentGens::new` builds the Ligero
+/// parameters directly, and `LigeroPolyCommitmentScheme::setup` calls it
+/// rather than the other way round. Selecting `LigeroPolyCommitmentScheme`
+/// keeps the transparent-setup behavior callers already depend on; switch to
+/// [`mlkzg::MultilinearKzgScheme`] (feature `mlkzg`) for succinct commitments
+/// at the cost of a one-time trusted setup.
+pub type DefaultPolyCommitmentScheme = LigeroPolyCommitmentScheme;
+
+#[cfg(feature = "mlkzg")]
+pub mod mlkzg {
+  //! A pairing-based multilinear polynomial-commitment scheme, following the
+  //! construction used by the Testudo variant of Spartan: commitments are a
+  //! single group element and evaluation proofs are logarithmic in the
+  //! polynomial size, at the cost of a per-size trusted setup.
+  use super::*;
+  use ark_poly_commit::multilinear_pc::data_structures::{
+    Commitment as ArkCommitment, CommitterKey, Proof as ArkProof, VerifierKey,
+  };
+  use ark_poly_commit::multilinear_pc::MultilinearPC;
+  #[cfg(feature = "insecure-test-only")]
+  use ark_std::rand::rngs::StdRng;
+  #[cfg(feature = "insecure-test-only")]
+  use ark_std::rand::SeedableRng;
+
+  /// Public parameters for the multilinear KZG scheme: a prover-side
+  /// committer key and a succinct verifier key, both sized for `num_vars`.
+  pub struct MultilinearKzgGens {
+    pub ck: CommitterKey<super::super::scalar::Pairing>,
+    pub vk: VerifierKey<super::super::scalar::Pairing>,
+  }
+
+  #[derive(Clone)]
+  pub struct MultilinearKzgCommitment {
+    pub comm: ArkCommitment<super::super::scalar::Pairing>,
+  }
+
+  pub struct MultilinearKzgDecommitment {
+    pub ck: CommitterKey<super::super::scalar::Pairing>,
+    pub evals: Vec<super::super::scalar::ArkScalar>,
+  }
+
+  pub struct MultilinearKzgEvalProof {
+    pub proof: ArkProof<super::super::scalar::Pairing>,
+  }
+
+  /// The succinct alternative to [`super::LigeroPolyCommitmentScheme`]: a
+  /// single group element per commitment and an O(num_vars)-size opening,
+  /// backed by `ark_poly_commit`'s `MultilinearPC`.
+  pub struct MultilinearKzgScheme;
+
+  impl PolyCommitmentScheme for MultilinearKzgScheme {
+    type Gens = MultilinearKzgGens;
+    type Commitment = MultilinearKzgCommitment;
+    type Decommitment = MultilinearKzgDecommitment;
+    type EvalProof = MultilinearKzgEvalProof;
+
+    /// # Panics
+    ///
+    /// Unless the `insecure-test-only` feature is enabled. The setup below
+    /// seeds its randomness deterministically from `num_vars`, so the KZG
+    /// trapdoor is recoverable by anyone who knows `num_vars` and every
+    /// commitment/opening produced from it is forgeable. That's acceptable
+    /// for tests and benchmarks, but this entry point must not be reachable
+    /// in a build anyone relies on for soundness; callers that need a real
+    /// setup should run a proper ceremony and feed the resulting `ck`/`vk`
+    /// into [`MultilinearKzgGens`] directly.
+    fn setup(num_vars: usize, _label: &'static [u8]) -> Self::Gens {
+      #[cfg(not(feature = "insecure-test-only"))]
+      panic!(
+        "MultilinearKzgScheme::setup derives its trapdoor deterministically from num_vars and is \
+         not binding; enable the `insecure-test-only` feature to use it for tests/benchmarks, or \
+         construct MultilinearKzgGens from a real trusted setup instead"
+      );
+
+      #[cfg(feature = "insecure-test-only")]
+      {
+        let mut rng = StdRng::seed_from_u64(num_vars as u64);
+        let pp = MultilinearPC::setup(num_vars, &mut rng);
+        let (ck, vk) = MultilinearPC::trim(&pp, num_vars);
+        MultilinearKzgGens { ck, vk }
+      }
+    }
+
+    fn commit(
+      poly: &DensePolynomial,
+      gens: &Self::Gens,
+      _random_tape: Option<&mut RandomTape<MerlinTranscript>>,
+    ) -> (Self::Commitment, Self::Decommitment) {
+      let evals = super::super::scalar::scalars_to_ark(poly.evals_ref());
+      let ark_poly = super::super::scalar::dense_ml_poly_from_evals(&evals);
+      let comm = MultilinearPC::commit(&gens.ck, &ark_poly);
+      (
+        MultilinearKzgCommitment { comm },
+        MultilinearKzgDecommitment {
+          ck: gens.ck.clone(),
+          evals,
+        },
+      )
+    }
+
+    fn prove(
+      decomm: &Self::Decommitment,
+      poly: &DensePolynomial,
+      r: &[Scalar],
+      Zr: &Scalar,
+      _gens: &Self::Gens,
+      _transcript: &mut MerlinTranscript,
+      _random_tape: &mut RandomTape<MerlinTranscript>,
+    ) -> (Self::EvalProof, Scalar) {
+      let ark_poly = super::super::scalar::dense_ml_poly_from_evals(&decomm.evals);
+      let ark_r = super::super::scalar::scalars_to_ark(r);
+      debug_assert_eq!(poly.get_num_vars(), r.len());
+      let proof = MultilinearPC::open(&decomm.ck, &ark_poly, &ark_r);
+      // the KZG backend doesn't yet implement a hiding mode, so the
+      // evaluation value the verifier checks against is simply `Zr`.
+      (MultilinearKzgEvalProof { proof }, *Zr)
+    }
+
+    fn verify(
+      proof: &Self::EvalProof,
+      gens: &Self::Gens,
+      _transcript: &mut MerlinTranscript,
+      r: &[Scalar],
+      eval: &Scalar,
+      comm: &Self::Commitment,
+    ) -> Result<(), ProofVerifyError> {
+      let ark_r = super::super::scalar::scalars_to_ark(r);
+      let ark_eval = super::super::scalar::scalar_to_ark(eval);
+      let ok = MultilinearPC::check(&gens.vk, &comm.comm, &ark_r, ark_eval, &proof.proof);
+      if ok {
+        Ok(())
+      } else {
+        Err(ProofVerifyError::InternalError)
+      }
+    }
+  }
+}
@@ -0,0 +1,184 @@
+use super::dense_mlpoly::{DensePolynomial, EqPolynomial};
+use super::scalar::Scalar;
+use super::sumcheck::SumcheckInstanceProof;
+use super::transcript::SpartanTranscript;
+use serde::{Deserialize, Serialize};
+
+/// A binary tree of layers over `v`'s evaluations, each layer the
+/// element-wise product of the bottom and top halves of the layer below,
+/// bottoming out at a single scalar: the product of all of `v`. This is the
+/// grand-product argument's circuit, used by [`super::sparse_mlpoly`] to
+/// prove multiset equality between a memory's read-set and write-set
+/// without ever expanding either set explicitly.
+pub struct ProductCircuit {
+  left_vec: Vec<DensePolynomial>,
+  right_vec: Vec<DensePolynomial>,
+}
+
+impl ProductCircuit {
+  pub fn new(v: &DensePolynomial) -> Self {
+    let mut left_vec = Vec::new();
+    let mut right_vec = Vec::new();
+    let mut cur = v.clone();
+    while cur.len() > 1 {
+      let (left, right) = cur.split(cur.len() / 2);
+      let n = left.len();
+      let next: Vec<Scalar> = (0..n).map(|i| left[i] * right[i]).collect();
+      left_vec.push(left);
+      right_vec.push(right);
+      cur = DensePolynomial::new(next);
+    }
+    ProductCircuit {
+      left_vec,
+      right_vec,
+    }
+  }
+
+  /// The product of every evaluation of the original `v`.
+  pub fn evaluate(&self) -> Scalar {
+    let left = self.left_vec.last().unwrap();
+    let right = self.right_vec.last().unwrap();
+    left[0] * right[0]
+  }
+
+  pub fn depth(&self) -> usize {
+    self.left_vec.len()
+  }
+}
+
+/// One layer's worth of the grand-product reduction: a sumcheck proof that
+/// `claim = \sum_x eq(rand, x) * left(x) * right(x)`, together with the
+/// final (unbound) evaluations of `left` and `right` the sumcheck reduces
+/// to. At the root layer (0 variables) there's nothing to sum-check, so
+/// `proof` is empty and `left_claim`/`right_claim` are simply the two
+/// values being multiplied.
+#[derive(Debug, Serialize, Deserialize)]
+struct LayerProof {
+  proof: SumcheckInstanceProof,
+  num_rounds: usize,
+  left_claim: Scalar,
+  right_claim: Scalar,
+}
+
+/// A proof that a [`ProductCircuit`]'s grand product equals a claimed
+/// value, reducing that claim layer by layer down to a single evaluation
+/// claim about the circuit's input polynomial `v` at a random point. The
+/// caller must separately check that reduced claim against `v`'s actual
+/// commitment (or recompute it directly, when `v` is public).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProductCircuitEvalProof {
+  layers: Vec<LayerProof>,
+}
+
+impl ProductCircuitEvalProof {
+  /// Generic over any `SpartanTranscript` backend: the grand-product
+  /// reduction only calls into [`SumcheckInstanceProof`], never into the
+  /// Ligero column-opening code, so it carries no `LigeroTranscript` bound
+  /// and can run end to end over `poseidon::PoseidonTranscript`.
+  pub fn prove<T: SpartanTranscript>(circuit: &ProductCircuit, transcript: &mut T) -> (Self, Scalar, Vec<Scalar>) {
+    let mut claim = circuit.evaluate();
+    let mut rand: Vec<Scalar> = Vec::new();
+    let mut layers = Vec::with_capacity(circuit.depth());
+
+    for idx in (0..circuit.depth()).rev() {
+      let mut left = circuit.left_vec[idx].clone();
+      let mut right = circuit.right_vec[idx].clone();
+      let num_rounds = left.get_num_vars();
+
+      let (proof, r, left_claim, right_claim) = if num_rounds == 0 {
+        assert_eq!(claim, left[0] * right[0]);
+        let (empty_proof, _, _) =
+          SumcheckInstanceProof::prove_quad(&claim, 0, &mut left, &mut right, transcript);
+        (empty_proof, Vec::new(), left[0], right[0])
+      } else {
+        let mut eq = DensePolynomial::new(EqPolynomial::new(rand.clone()).evals());
+        let (proof, r, (_eq_final, left_final, right_final)) =
+          SumcheckInstanceProof::prove_cubic(&claim, num_rounds, &mut eq, &mut left, &mut right, transcript);
+        (proof, r, left_final, right_final)
+      };
+
+      let rho: Scalar = transcript.challenge_scalar(b"product_layer_rho");
+      layers.push(LayerProof {
+        proof,
+        num_rounds,
+        left_claim,
+        right_claim,
+      });
+      claim = left_claim + rho * (right_claim - left_claim);
+      rand = std::iter::once(rho).chain(r).collect();
+    }
+
+    (ProductCircuitEvalProof { layers }, claim, rand)
+  }
+
+  pub fn verify<T: SpartanTranscript>(&self, claim: Scalar, transcript: &mut T) -> (Scalar, Vec<Scalar>) {
+    let mut claim = claim;
+    let mut rand: Vec<Scalar> = Vec::new();
+
+    for layer in &self.layers {
+      let r = if layer.num_rounds == 0 {
+        assert_eq!(claim, layer.left_claim * layer.right_claim);
+        Vec::new()
+      } else {
+        let (final_eval, r) = layer.proof.verify(claim, layer.num_rounds, 3, transcript);
+        let eq_final = EqPolynomial::new(rand.clone()).evaluate(&r);
+        assert_eq!(final_eval, eq_final * layer.left_claim * layer.right_claim);
+        r
+      };
+
+      let rho: Scalar = transcript.challenge_scalar(b"product_layer_rho");
+      claim = layer.left_claim + rho * (layer.right_claim - layer.left_claim);
+      rand = std::iter::once(rho).chain(r).collect();
+    }
+
+    (claim, rand)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::super::scalar::ScalarFromPrimitives;
+  use super::super::transcript::MerlinTranscript;
+
+  #[test]
+  fn check_product_circuit_eval_proof_round_trip() {
+    let v = DensePolynomial::new((1..=8usize).map(|i| i.to_scalar()).collect());
+    let circuit = ProductCircuit::new(&v);
+    let claim = circuit.evaluate();
+    assert_eq!(claim, (40320 as usize).to_scalar()); // 8!
+
+    let mut prover_transcript = MerlinTranscript::new(b"product-circuit-test");
+    let (proof, final_claim, rand) = ProductCircuitEvalProof::prove(&circuit, &mut prover_transcript);
+
+    let mut verifier_transcript = MerlinTranscript::new(b"product-circuit-test");
+    let (verify_claim, verify_rand) = proof.verify(claim, &mut verifier_transcript);
+
+    assert_eq!(final_claim, verify_claim);
+    assert_eq!(rand, verify_rand);
+    assert_eq!(final_claim, v.evaluate(&verify_rand));
+  }
+
+  // Demonstrates the payoff requests.jsonl#chunk0-6 asked for: the
+  // grand-product circuit has no `LigeroTranscript` bound, so it can
+  // actually be driven end to end by the Poseidon sponge backend.
+  #[cfg(feature = "poseidon")]
+  #[test]
+  fn check_product_circuit_eval_proof_round_trip_poseidon() {
+    use super::super::transcript::poseidon::PoseidonTranscript;
+
+    let v = DensePolynomial::new((1..=8usize).map(|i| i.to_scalar()).collect());
+    let circuit = ProductCircuit::new(&v);
+    let claim = circuit.evaluate();
+
+    let mut prover_transcript = PoseidonTranscript::new(b"product-circuit-test");
+    let (proof, final_claim, rand) = ProductCircuitEvalProof::prove(&circuit, &mut prover_transcript);
+
+    let mut verifier_transcript = PoseidonTranscript::new(b"product-circuit-test");
+    let (verify_claim, verify_rand) = proof.verify(claim, &mut verifier_transcript);
+
+    assert_eq!(final_claim, verify_claim);
+    assert_eq!(rand, verify_rand);
+    assert_eq!(final_claim, v.evaluate(&verify_rand));
+  }
+}
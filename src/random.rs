@@ -1,25 +1,39 @@
 use super::scalar::Scalar;
-use super::transcript::ProofTranscript;
+use super::transcript::SpartanTranscript;
 use ff::Field;
-use merlin::Transcript;
 use rand_core::OsRng;
 
-pub struct RandomTape {
-  tape: Transcript,
+pub struct RandomTape<T: SpartanTranscript> {
+  tape: T,
 }
 
-impl RandomTape {
+impl<T: SpartanTranscript> RandomTape<T> {
   pub fn new(name: &'static [u8]) -> Self {
     let tape = {
       let mut csprng: OsRng = OsRng;
-      let mut tape = Transcript::new(name);
+      let mut tape = T::new(name);
       tape.append_scalar(b"init_randomness", &Scalar::random(&mut csprng));
       tape
     };
     Self { tape }
   }
 
+  // Fiat-Shamir challenges must be a deterministic function of the
+  // transcript so the verifier can recompute them, but blinding factors are
+  // the opposite: a prover that could predict its own blinds from the
+  // public transcript would leak exactly what hiding is meant to hide. Mix
+  // in fresh `OsRng` bytes before every draw so each call is unpredictable
+  // even to the prover itself — the draw is not reproducible by anyone,
+  // including whoever sampled it, which is exactly what a blind needs.
   pub fn random_scalar(&mut self, label: &'static [u8]) -> Scalar {
+    let mut csprng: OsRng = OsRng;
+    self.tape.append_scalar(b"entropy", &Scalar::random(&mut csprng));
     self.tape.challenge_scalar(label)
   }
+
+  /// Draws `len` fresh scalars, e.g. to fill a Ligero hiding row, one scalar
+  /// per entry of the commitment's row width.
+  pub fn random_vector(&mut self, label: &'static [u8], len: usize) -> Vec<Scalar> {
+    (0..len).map(|_| self.random_scalar(label)).collect()
+  }
 }
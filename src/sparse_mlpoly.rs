@@ -0,0 +1,652 @@
+#![allow(clippy::too_many_arguments)]
+use super::dense_mlpoly::{
+  DensePolynomial, EqPolynomial, IdentityPolynomial, PolyCommitment, PolyCommitmentGens,
+  PolyDecommitment, PolyEvalProof,
+};
+use super::errors::ProofVerifyError;
+use super::math::Math;
+use super::product_tree::{ProductCircuit, ProductCircuitEvalProof};
+use super::scalar::Scalar;
+use super::sumcheck::SumcheckInstanceProof;
+use super::transcript::{AppendToTranscript, LigeroTranscript, MerlinTranscript, SpartanTranscript};
+use ff::Field;
+use serde::{Deserialize, Serialize};
+
+/// One nonzero entry of a sparse matrix over the Boolean hypercube, e.g. a
+/// row of an R1CS constraint matrix.
+#[derive(Clone, Copy, Debug)]
+pub struct SparseMatEntry {
+  pub row: usize,
+  pub col: usize,
+  pub val: Scalar,
+}
+
+impl SparseMatEntry {
+  pub fn new(row: usize, col: usize, val: Scalar) -> Self {
+    SparseMatEntry { row, col, val }
+  }
+}
+
+/// A sparse multilinear polynomial over `{0,1}^num_vars_x x {0,1}^num_vars_y`,
+/// represented by its nonzero `(row, col, val)` entries. [`Self::multi_commit`]
+/// commits to it with cost proportional to its nonzero count rather than
+/// `2^(num_vars_x + num_vars_y)`, the way Spartan commits to R1CS matrices.
+pub struct SparseMatPolynomial {
+  num_vars_x: usize,
+  num_vars_y: usize,
+  M: Vec<SparseMatEntry>,
+}
+
+impl SparseMatPolynomial {
+  pub fn new(num_vars_x: usize, num_vars_y: usize, M: Vec<SparseMatEntry>) -> Self {
+    SparseMatPolynomial {
+      num_vars_x,
+      num_vars_y,
+      M,
+    }
+  }
+
+  pub fn get_num_nz_entries(&self) -> usize {
+    self.M.len().next_power_of_two()
+  }
+
+  /// Evaluates `M(rx, ry)` directly in `O(nnz)` time. Used by the prover (to
+  /// derive the claim it proves) and by callers who already have the matrix
+  /// in the clear; the succinct reduction lives in [`SparseMatPolyEvalProof`].
+  pub fn multi_evaluate(&self, rx: &[Scalar], ry: &[Scalar]) -> Scalar {
+    assert_eq!(rx.len(), self.num_vars_x);
+    assert_eq!(ry.len(), self.num_vars_y);
+    let eq_rx = EqPolynomial::new(rx.to_vec()).evals();
+    let eq_ry = EqPolynomial::new(ry.to_vec()).evals();
+    self
+      .M
+      .iter()
+      .map(|e| eq_rx[e.row] * eq_ry[e.col] * e.val)
+      .sum()
+  }
+
+  /// Commits the matrix as a SPARK-style encoding: the nonzero `(row, col,
+  /// val)` triples as dense polynomials, plus the read/final-audit timestamp
+  /// polynomials (one pair per dimension) that let an evaluation proof later
+  /// establish, via offline memory checking, that a claimed lookup value was
+  /// read from the right address. The timestamps depend only on the access
+  /// pattern of `row`/`col`, not on an evaluation point, so they're
+  /// committed once here rather than per-proof.
+  pub fn multi_commit(
+    &self,
+    gens: &SparseMatPolyCommitmentGens,
+  ) -> (SparseMatPolyCommitment, SparseMatPolyDecommitment) {
+    let num_nz = self.get_num_nz_entries();
+    let nx = self.num_vars_x.pow2();
+    let ny = self.num_vars_y.pow2();
+
+    let mut row_idx = vec![0usize; num_nz];
+    let mut col_idx = vec![0usize; num_nz];
+    let mut val_vals = vec![Scalar::zero(); num_nz];
+    for (i, e) in self.M.iter().enumerate() {
+      row_idx[i] = e.row;
+      col_idx[i] = e.col;
+      val_vals[i] = e.val;
+    }
+
+    let row = DensePolynomial::from_usize(&row_idx);
+    let col = DensePolynomial::from_usize(&col_idx);
+    let val = DensePolynomial::new(val_vals);
+
+    let (row_read_ts_raw, row_audit_ts_raw) = compute_ts(&row_idx, nx);
+    let (col_read_ts_raw, col_audit_ts_raw) = compute_ts(&col_idx, ny);
+    let row_read_ts = DensePolynomial::from_usize(&row_read_ts_raw);
+    let col_read_ts = DensePolynomial::from_usize(&col_read_ts_raw);
+    let row_audit_ts = DensePolynomial::from_usize(&row_audit_ts_raw);
+    let col_audit_ts = DensePolynomial::from_usize(&col_audit_ts_raw);
+
+    let (comm_row, decomm_row) = row.commit(&gens.gens_nnz, None);
+    let (comm_col, decomm_col) = col.commit(&gens.gens_nnz, None);
+    let (comm_val, decomm_val) = val.commit(&gens.gens_nnz, None);
+    let (comm_row_read_ts, decomm_row_read_ts) = row_read_ts.commit(&gens.gens_nnz, None);
+    let (comm_col_read_ts, decomm_col_read_ts) = col_read_ts.commit(&gens.gens_nnz, None);
+    let (comm_row_audit_ts, decomm_row_audit_ts) = row_audit_ts.commit(&gens.gens_x, None);
+    let (comm_col_audit_ts, decomm_col_audit_ts) = col_audit_ts.commit(&gens.gens_y, None);
+
+    (
+      SparseMatPolyCommitment {
+        comm_row,
+        comm_col,
+        comm_val,
+        comm_row_read_ts,
+        comm_col_read_ts,
+        comm_row_audit_ts,
+        comm_col_audit_ts,
+        num_nz,
+        num_vars_x: self.num_vars_x,
+        num_vars_y: self.num_vars_y,
+      },
+      SparseMatPolyDecommitment {
+        row,
+        col,
+        val,
+        row_read_ts,
+        col_read_ts,
+        row_audit_ts,
+        col_audit_ts,
+        decomm_row,
+        decomm_col,
+        decomm_val,
+        decomm_row_read_ts,
+        decomm_col_read_ts,
+        decomm_row_audit_ts,
+        decomm_col_audit_ts,
+        row_idx,
+        col_idx,
+      },
+    )
+  }
+}
+
+/// `SparseMatPolynomial::multi_commit`'s public output: the Merkle roots of
+/// the matrix's own dense encoding plus its per-dimension timestamp polynomials.
+pub struct SparseMatPolyCommitment {
+  comm_row: PolyCommitment,
+  comm_col: PolyCommitment,
+  comm_val: PolyCommitment,
+  comm_row_read_ts: PolyCommitment,
+  comm_col_read_ts: PolyCommitment,
+  comm_row_audit_ts: PolyCommitment,
+  comm_col_audit_ts: PolyCommitment,
+  num_nz: usize,
+  num_vars_x: usize,
+  num_vars_y: usize,
+}
+
+/// The prover's half of [`SparseMatPolyCommitment`]: the dense polynomials
+/// themselves plus their Ligero decommitments, retained so a later
+/// evaluation proof doesn't have to recompute or re-commit them.
+pub struct SparseMatPolyDecommitment {
+  row: DensePolynomial,
+  col: DensePolynomial,
+  val: DensePolynomial,
+  row_read_ts: DensePolynomial,
+  col_read_ts: DensePolynomial,
+  row_audit_ts: DensePolynomial,
+  col_audit_ts: DensePolynomial,
+  decomm_row: PolyDecommitment,
+  decomm_col: PolyDecommitment,
+  decomm_val: PolyDecommitment,
+  decomm_row_read_ts: PolyDecommitment,
+  decomm_col_read_ts: PolyDecommitment,
+  decomm_row_audit_ts: PolyDecommitment,
+  decomm_col_audit_ts: PolyDecommitment,
+  row_idx: Vec<usize>,
+  col_idx: Vec<usize>,
+}
+
+/// Sized public parameters for a [`SparseMatPolynomial`] commitment: one
+/// `PolyCommitmentGens` for the nonzero-count-sized polynomials (row, col,
+/// val, read timestamps, and the per-evaluation lookup polynomials), and one
+/// each for the row-space- and column-space-sized audit-timestamp polynomials.
+pub struct SparseMatPolyCommitmentGens {
+  gens_nnz: PolyCommitmentGens,
+  gens_x: PolyCommitmentGens,
+  gens_y: PolyCommitmentGens,
+}
+
+impl SparseMatPolyCommitmentGens {
+  pub fn new(
+    num_vars_x: usize,
+    num_vars_y: usize,
+    num_nz_entries: usize,
+    label: &'static [u8],
+  ) -> Self {
+    let num_vars_nnz = num_nz_entries.next_power_of_two().log2();
+    SparseMatPolyCommitmentGens {
+      gens_nnz: PolyCommitmentGens::new(num_vars_nnz, label),
+      gens_x: PolyCommitmentGens::new(num_vars_x, label),
+      gens_y: PolyCommitmentGens::new(num_vars_y, label),
+    }
+  }
+}
+
+/// For each address `a` visited by `addr` (in order), `read_ts[i]` is how
+/// many times `addr[i]` was visited before index `i`, and the returned
+/// `audit_ts` vector holds, per address, the total number of visits.
+fn compute_ts(addr: &[usize], num_addrs: usize) -> (Vec<usize>, Vec<usize>) {
+  let mut counter = vec![0usize; num_addrs];
+  let mut read_ts = Vec::with_capacity(addr.len());
+  for &a in addr {
+    read_ts.push(counter[a]);
+    counter[a] += 1;
+  }
+  (read_ts, counter)
+}
+
+/// A random-linear-combination fingerprint `val + addr*gamma + ts*gamma^2 -
+/// tau` of a memory access. Two access multisets are equal (with
+/// overwhelming probability over the choice of `gamma`, `tau`) iff the
+/// products of their members' fingerprints are equal — the identity
+/// [`prove_mem_check`]/[`verify_mem_check`] check via [`ProductCircuit`].
+fn fingerprint(addr: Scalar, val: Scalar, ts: Scalar, gamma: Scalar, tau: Scalar) -> Scalar {
+  val + addr * gamma + ts * gamma * gamma - tau
+}
+
+/// One committed polynomial opened at a sumcheck-reduced point.
+#[derive(Debug, Serialize, Deserialize)]
+struct OpenedEval {
+  proof: PolyEvalProof,
+  eval: Scalar,
+}
+
+fn open_poly<T: SpartanTranscript + LigeroTranscript>(
+  poly: &DensePolynomial,
+  decomm: &PolyDecommitment,
+  gens: &PolyCommitmentGens,
+  r: &[Scalar],
+  transcript: &mut T,
+) -> OpenedEval {
+  let eval = poly.evaluate(r);
+  let (proof, eval_commit) = PolyEvalProof::prove(poly, decomm, r, &eval, gens, transcript);
+  debug_assert_eq!(eval, eval_commit);
+  OpenedEval { proof, eval }
+}
+
+/// An offline-memory-checking proof that `e[i] = mem[addr[i]]` for every
+/// `i`, where `mem` is a public table (here, the evaluations of `eq(point,
+/// ·)`) and `addr`/`e` are committed polynomials. Reduces to four grand
+/// products (one each for the memory's initial state, the read set, the
+/// read-then-write-back set, and the final audited state) via
+/// [`ProductCircuitEvalProof`], each in turn reduced to openings of the
+/// address/value/timestamp polynomials at a sumcheck-derived point.
+#[derive(Debug, Serialize, Deserialize)]
+struct MemCheckProof {
+  init_claim: Scalar,
+  write_claim: Scalar,
+  read_claim: Scalar,
+  audit_claim: Scalar,
+  init_proof: ProductCircuitEvalProof,
+  write_proof: ProductCircuitEvalProof,
+  read_proof: ProductCircuitEvalProof,
+  audit_proof: ProductCircuitEvalProof,
+  audit_ts: OpenedEval,
+  read_addr: OpenedEval,
+  read_e: OpenedEval,
+  read_ts: OpenedEval,
+  write_addr: OpenedEval,
+  write_e: OpenedEval,
+  write_ts: OpenedEval,
+}
+
+fn prove_mem_check<T: SpartanTranscript + LigeroTranscript>(
+  addr_padded: &[usize],
+  addr_poly: &DensePolynomial,
+  addr_decomm: &PolyDecommitment,
+  read_ts_poly: &DensePolynomial,
+  read_ts_decomm: &PolyDecommitment,
+  audit_ts_poly: &DensePolynomial,
+  audit_ts_decomm: &PolyDecommitment,
+  num_vars_addr: usize,
+  point: &[Scalar],
+  gens_nnz: &PolyCommitmentGens,
+  gens_addr: &PolyCommitmentGens,
+  transcript: &mut T,
+) -> (MemCheckProof, DensePolynomial, PolyCommitment, PolyDecommitment) {
+  let nnz = addr_poly.len();
+  let nx = num_vars_addr.pow2();
+
+  let eq_vals = EqPolynomial::new(point.to_vec()).evals();
+  let e_vals: Vec<Scalar> = addr_padded.iter().map(|&a| eq_vals[a]).collect();
+  let e_poly = DensePolynomial::new(e_vals);
+  let (comm_e, decomm_e) = e_poly.commit(gens_nnz, None);
+  comm_e.append_to_transcript(b"mem_check_e", transcript);
+
+  let gamma: Scalar = transcript.challenge_scalar(b"memcheck_gamma");
+  let tau: Scalar = transcript.challenge_scalar(b"memcheck_tau");
+
+  let init_vals: Vec<Scalar> = (0..nx)
+    .map(|a| fingerprint(Scalar::from(a as u64), eq_vals[a], Scalar::zero(), gamma, tau))
+    .collect();
+  let audit_vals: Vec<Scalar> = (0..nx)
+    .map(|a| fingerprint(Scalar::from(a as u64), eq_vals[a], audit_ts_poly[a], gamma, tau))
+    .collect();
+  let read_vals: Vec<Scalar> = (0..nnz)
+    .map(|i| {
+      fingerprint(
+        Scalar::from(addr_padded[i] as u64),
+        e_poly[i],
+        read_ts_poly[i],
+        gamma,
+        tau,
+      )
+    })
+    .collect();
+  let write_vals: Vec<Scalar> = (0..nnz)
+    .map(|i| {
+      fingerprint(
+        Scalar::from(addr_padded[i] as u64),
+        e_poly[i],
+        read_ts_poly[i] + Scalar::one(),
+        gamma,
+        tau,
+      )
+    })
+    .collect();
+
+  let init_circuit = ProductCircuit::new(&DensePolynomial::new(init_vals));
+  let audit_circuit = ProductCircuit::new(&DensePolynomial::new(audit_vals));
+  let read_circuit = ProductCircuit::new(&DensePolynomial::new(read_vals));
+  let write_circuit = ProductCircuit::new(&DensePolynomial::new(write_vals));
+
+  let init_claim = init_circuit.evaluate();
+  let audit_claim = audit_circuit.evaluate();
+  let read_claim = read_circuit.evaluate();
+  let write_claim = write_circuit.evaluate();
+  // the read-only-memory offline-checking identity: every address is
+  // written once at init and once more after every read, and read exactly
+  // as many times as the final audit records.
+  assert_eq!(init_claim * write_claim, read_claim * audit_claim);
+
+  let (init_proof, init_final, rand_init) = ProductCircuitEvalProof::prove(&init_circuit, transcript);
+  let (audit_proof, audit_final, rand_audit) = ProductCircuitEvalProof::prove(&audit_circuit, transcript);
+  let (read_proof, read_final, rand_read) = ProductCircuitEvalProof::prove(&read_circuit, transcript);
+  let (write_proof, write_final, rand_write) = ProductCircuitEvalProof::prove(&write_circuit, transcript);
+
+  // `init` is a deterministic public function of `point`, so it needs no
+  // commitment opening; sanity-check it here (the verifier redoes this).
+  debug_assert_eq!(
+    init_final,
+    fingerprint(
+      IdentityPolynomial::new(num_vars_addr).evaluate(&rand_init),
+      EqPolynomial::new(point.to_vec()).evaluate(&rand_init),
+      Scalar::zero(),
+      gamma,
+      tau
+    )
+  );
+
+  let audit_ts = open_poly(audit_ts_poly, audit_ts_decomm, gens_addr, &rand_audit, transcript);
+  let read_addr = open_poly(addr_poly, addr_decomm, gens_nnz, &rand_read, transcript);
+  let read_e = open_poly(&e_poly, &decomm_e, gens_nnz, &rand_read, transcript);
+  let read_ts = open_poly(read_ts_poly, read_ts_decomm, gens_nnz, &rand_read, transcript);
+  let write_addr = open_poly(addr_poly, addr_decomm, gens_nnz, &rand_write, transcript);
+  let write_e = open_poly(&e_poly, &decomm_e, gens_nnz, &rand_write, transcript);
+  let write_ts = open_poly(read_ts_poly, read_ts_decomm, gens_nnz, &rand_write, transcript);
+
+  (
+    MemCheckProof {
+      init_claim,
+      write_claim,
+      read_claim,
+      audit_claim,
+      init_proof,
+      write_proof,
+      read_proof,
+      audit_proof,
+      audit_ts,
+      read_addr,
+      read_e,
+      read_ts,
+      write_addr,
+      write_e,
+      write_ts,
+    },
+    e_poly,
+    comm_e,
+    decomm_e,
+  )
+}
+
+fn verify_mem_check<T: SpartanTranscript + LigeroTranscript>(
+  proof: &MemCheckProof,
+  comm_addr: &PolyCommitment,
+  comm_read_ts: &PolyCommitment,
+  comm_audit_ts: &PolyCommitment,
+  comm_e: &PolyCommitment,
+  num_vars_addr: usize,
+  point: &[Scalar],
+  gens_nnz: &PolyCommitmentGens,
+  gens_addr: &PolyCommitmentGens,
+  transcript: &mut T,
+) -> Result<(), ProofVerifyError> {
+  comm_e.append_to_transcript(b"mem_check_e", transcript);
+  let gamma: Scalar = transcript.challenge_scalar(b"memcheck_gamma");
+  let tau: Scalar = transcript.challenge_scalar(b"memcheck_tau");
+
+  if proof.init_claim * proof.write_claim != proof.read_claim * proof.audit_claim {
+    return Err(ProofVerifyError::InternalError);
+  }
+
+  let (init_final, rand_init) = proof.init_proof.verify(proof.init_claim, transcript);
+  let (audit_final, rand_audit) = proof.audit_proof.verify(proof.audit_claim, transcript);
+  let (read_final, rand_read) = proof.read_proof.verify(proof.read_claim, transcript);
+  let (write_final, rand_write) = proof.write_proof.verify(proof.write_claim, transcript);
+
+  let identity = IdentityPolynomial::new(num_vars_addr);
+  let eq_point = EqPolynomial::new(point.to_vec());
+
+  let expected_init = fingerprint(identity.evaluate(&rand_init), eq_point.evaluate(&rand_init), Scalar::zero(), gamma, tau);
+  if init_final != expected_init {
+    return Err(ProofVerifyError::InternalError);
+  }
+
+  proof.audit_ts.proof.verify(gens_addr, transcript, &rand_audit, &proof.audit_ts.eval, comm_audit_ts)?;
+  let expected_audit = fingerprint(
+    identity.evaluate(&rand_audit),
+    eq_point.evaluate(&rand_audit),
+    proof.audit_ts.eval,
+    gamma,
+    tau,
+  );
+  if audit_final != expected_audit {
+    return Err(ProofVerifyError::InternalError);
+  }
+
+  proof.read_addr.proof.verify(gens_nnz, transcript, &rand_read, &proof.read_addr.eval, comm_addr)?;
+  proof.read_e.proof.verify(gens_nnz, transcript, &rand_read, &proof.read_e.eval, comm_e)?;
+  proof.read_ts.proof.verify(gens_nnz, transcript, &rand_read, &proof.read_ts.eval, comm_read_ts)?;
+  let expected_read = fingerprint(proof.read_addr.eval, proof.read_e.eval, proof.read_ts.eval, gamma, tau);
+  if read_final != expected_read {
+    return Err(ProofVerifyError::InternalError);
+  }
+
+  proof.write_addr.proof.verify(gens_nnz, transcript, &rand_write, &proof.write_addr.eval, comm_addr)?;
+  proof.write_e.proof.verify(gens_nnz, transcript, &rand_write, &proof.write_e.eval, comm_e)?;
+  proof.write_ts.proof.verify(gens_nnz, transcript, &rand_write, &proof.write_ts.eval, comm_read_ts)?;
+  let expected_write = fingerprint(
+    proof.write_addr.eval,
+    proof.write_e.eval,
+    proof.write_ts.eval + Scalar::one(),
+    gamma,
+    tau,
+  );
+  if write_final != expected_write {
+    return Err(ProofVerifyError::InternalError);
+  }
+
+  Ok(())
+}
+
+/// A succinct evaluation proof for `M(rx, ry)`, reducing it to openings of
+/// [`SparseMatPolyCommitment`]'s dense polynomials: the row- and
+/// column-dimension offline-memory-checking arguments establish that the
+/// freshly-committed lookup polynomials really hold `eq(rx, row[i])` and
+/// `eq(ry, col[i])`, and a final cubic sumcheck reduces the claimed
+/// evaluation to openings of those lookup polynomials and `val`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SparseMatPolyEvalProof {
+  comm_e_rx: PolyCommitment,
+  comm_e_ry: PolyCommitment,
+  row_mem: MemCheckProof,
+  col_mem: MemCheckProof,
+  combine_proof: SumcheckInstanceProof,
+  e_rx_opening: OpenedEval,
+  e_ry_opening: OpenedEval,
+  val_opening: OpenedEval,
+}
+
+impl SparseMatPolyEvalProof {
+  fn protocol_name() -> &'static [u8] {
+    b"sparse polynomial evaluation proof"
+  }
+
+  /// Generic over any `T: SpartanTranscript + LigeroTranscript` rather than
+  /// fixed to `MerlinTranscript`: the sumcheck and grand-product reductions
+  /// underneath only need `SpartanTranscript`, but the dense-polynomial
+  /// openings this reduces to ([`open_poly`]) still go through the vendored
+  /// Ligero column-opening code, so the `LigeroTranscript` bound — and with
+  /// it the restriction to `MerlinTranscript`, its only implementor — can't
+  /// be dropped without replacing that opening path; see `LigeroTranscript`'s
+  /// doc comment.
+  pub fn prove<T: SpartanTranscript + LigeroTranscript>(
+    poly: &SparseMatPolynomial,
+    decomm: &SparseMatPolyDecommitment,
+    rx: &[Scalar],
+    ry: &[Scalar],
+    eval: &Scalar,
+    gens: &SparseMatPolyCommitmentGens,
+    transcript: &mut T,
+  ) -> Self {
+    transcript.append_protocol_name(SparseMatPolyEvalProof::protocol_name());
+    assert_eq!(rx.len(), poly.num_vars_x);
+    assert_eq!(ry.len(), poly.num_vars_y);
+
+    let (row_mem, e_rx_poly, comm_e_rx, decomm_e_rx) = prove_mem_check(
+      &decomm.row_idx,
+      &decomm.row,
+      &decomm.decomm_row,
+      &decomm.row_read_ts,
+      &decomm.decomm_row_read_ts,
+      &decomm.row_audit_ts,
+      &decomm.decomm_row_audit_ts,
+      poly.num_vars_x,
+      rx,
+      &gens.gens_nnz,
+      &gens.gens_x,
+      transcript,
+    );
+    let (col_mem, e_ry_poly, comm_e_ry, decomm_e_ry) = prove_mem_check(
+      &decomm.col_idx,
+      &decomm.col,
+      &decomm.decomm_col,
+      &decomm.col_read_ts,
+      &decomm.decomm_col_read_ts,
+      &decomm.col_audit_ts,
+      &decomm.decomm_col_audit_ts,
+      poly.num_vars_y,
+      ry,
+      &gens.gens_nnz,
+      &gens.gens_y,
+      transcript,
+    );
+
+    let num_rounds = decomm.val.get_num_vars();
+    debug_assert_eq!(
+      *eval,
+      (0..decomm.val.len())
+        .map(|i| e_rx_poly[i] * e_ry_poly[i] * decomm.val[i])
+        .sum::<Scalar>()
+    );
+
+    let mut e_rx_sc = e_rx_poly.clone();
+    let mut e_ry_sc = e_ry_poly.clone();
+    let mut val_sc = decomm.val.clone();
+    let (combine_proof, r_final, _) =
+      SumcheckInstanceProof::prove_cubic(eval, num_rounds, &mut e_rx_sc, &mut e_ry_sc, &mut val_sc, transcript);
+
+    let e_rx_opening = open_poly(&e_rx_poly, &decomm_e_rx, &gens.gens_nnz, &r_final, transcript);
+    let e_ry_opening = open_poly(&e_ry_poly, &decomm_e_ry, &gens.gens_nnz, &r_final, transcript);
+    let val_opening = open_poly(&decomm.val, &decomm.decomm_val, &gens.gens_nnz, &r_final, transcript);
+
+    SparseMatPolyEvalProof {
+      comm_e_rx,
+      comm_e_ry,
+      row_mem,
+      col_mem,
+      combine_proof,
+      e_rx_opening,
+      e_ry_opening,
+      val_opening,
+    }
+  }
+
+  pub fn verify<T: SpartanTranscript + LigeroTranscript>(
+    &self,
+    comm: &SparseMatPolyCommitment,
+    rx: &[Scalar],
+    ry: &[Scalar],
+    eval: &Scalar,
+    gens: &SparseMatPolyCommitmentGens,
+    transcript: &mut T,
+  ) -> Result<(), ProofVerifyError> {
+    transcript.append_protocol_name(SparseMatPolyEvalProof::protocol_name());
+    assert_eq!(rx.len(), comm.num_vars_x);
+    assert_eq!(ry.len(), comm.num_vars_y);
+
+    verify_mem_check(
+      &self.row_mem,
+      &comm.comm_row,
+      &comm.comm_row_read_ts,
+      &comm.comm_row_audit_ts,
+      &self.comm_e_rx,
+      comm.num_vars_x,
+      rx,
+      &gens.gens_nnz,
+      &gens.gens_x,
+      transcript,
+    )?;
+    verify_mem_check(
+      &self.col_mem,
+      &comm.comm_col,
+      &comm.comm_col_read_ts,
+      &comm.comm_col_audit_ts,
+      &self.comm_e_ry,
+      comm.num_vars_y,
+      ry,
+      &gens.gens_nnz,
+      &gens.gens_y,
+      transcript,
+    )?;
+
+    let num_rounds = comm.num_nz.log2();
+    let (final_eval, r_final) = self.combine_proof.verify(*eval, num_rounds, 3, transcript);
+
+    self.e_rx_opening.proof.verify(&gens.gens_nnz, transcript, &r_final, &self.e_rx_opening.eval, &self.comm_e_rx)?;
+    self.e_ry_opening.proof.verify(&gens.gens_nnz, transcript, &r_final, &self.e_ry_opening.eval, &self.comm_e_ry)?;
+    self.val_opening.proof.verify(&gens.gens_nnz, transcript, &r_final, &self.val_opening.eval, &comm.comm_val)?;
+
+    if final_eval != self.e_rx_opening.eval * self.e_ry_opening.eval * self.val_opening.eval {
+      return Err(ProofVerifyError::InternalError);
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::super::scalar::ScalarFromPrimitives;
+
+  #[test]
+  fn check_sparse_mat_poly_eval_proof_round_trip() {
+    let num_vars_x = 3;
+    let num_vars_y = 3;
+    let M = vec![
+      SparseMatEntry::new(0, 1, (2 as usize).to_scalar()),
+      SparseMatEntry::new(2, 3, (3 as usize).to_scalar()),
+      SparseMatEntry::new(5, 0, (4 as usize).to_scalar()),
+      SparseMatEntry::new(7, 6, (5 as usize).to_scalar()),
+    ];
+    let poly = SparseMatPolynomial::new(num_vars_x, num_vars_y, M);
+
+    let rx: Vec<Scalar> = (0..num_vars_x).map(|i| ((i + 2) as usize).to_scalar()).collect();
+    let ry: Vec<Scalar> = (0..num_vars_y).map(|i| ((i + 5) as usize).to_scalar()).collect();
+    let eval = poly.multi_evaluate(&rx, &ry);
+
+    let gens = SparseMatPolyCommitmentGens::new(num_vars_x, num_vars_y, poly.get_num_nz_entries(), b"test-sparse");
+    let (comm, decomm) = poly.multi_commit(&gens);
+
+    let mut prover_transcript = MerlinTranscript::new(b"example");
+    let proof = SparseMatPolyEvalProof::prove(&poly, &decomm, &rx, &ry, &eval, &gens, &mut prover_transcript);
+
+    let mut verifier_transcript = MerlinTranscript::new(b"example");
+    assert!(proof.verify(&comm, &rx, &ry, &eval, &gens, &mut verifier_transcript).is_ok());
+  }
+}
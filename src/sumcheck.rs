@@ -0,0 +1,205 @@
+use super::dense_mlpoly::DensePolynomial;
+use super::scalar::Scalar;
+use super::transcript::SpartanTranscript;
+use super::unipoly::UniPoly;
+use ff::Field;
+use serde::{Deserialize, Serialize};
+
+/// A sumcheck proof for a claim of the form
+/// `claim = \sum_{x \in \{0,1\}^n} f_1(x) \cdots f_k(x)`
+/// where each `f_i` is a multilinear polynomial, `k` is at most 3 in this
+/// crate, and `n` is `round_polys.len()`. Round `i` binds one variable of
+/// every factor via [`DensePolynomial::bound_poly_var_top`] and sends the
+/// verifier the restricted univariate polynomial in that variable, sampled
+/// at `0, 1, ..., k`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SumcheckInstanceProof {
+  round_polys: Vec<UniPoly>,
+}
+
+impl SumcheckInstanceProof {
+  /// Proves `claim = \sum_x a(x) * b(x)`. Generic over any `SpartanTranscript`
+  /// backend: unlike [`super::dense_mlpoly::PolyEvalProof`], sumcheck never
+  /// hands the transcript to the vendored Ligero column-opening code, so it
+  /// carries no `LigeroTranscript` bound and can run end to end over
+  /// `poseidon::PoseidonTranscript`.
+  pub fn prove_quad<T: SpartanTranscript>(
+    claim: &Scalar,
+    num_rounds: usize,
+    poly_a: &mut DensePolynomial,
+    poly_b: &mut DensePolynomial,
+    transcript: &mut T,
+  ) -> (Self, Vec<Scalar>, (Scalar, Scalar)) {
+    let mut claim = *claim;
+    let mut r = Vec::with_capacity(num_rounds);
+    let mut round_polys = Vec::with_capacity(num_rounds);
+
+    for _ in 0..num_rounds {
+      let n = poly_a.len() / 2;
+      let mut evals = [Scalar::zero(); 3];
+      for (t, eval) in evals.iter_mut().enumerate() {
+        let tsc = Scalar::from(t as u64);
+        *eval = (0..n)
+          .map(|i| {
+            let a = poly_a[i] + tsc * (poly_a[i + n] - poly_a[i]);
+            let b = poly_b[i] + tsc * (poly_b[i + n] - poly_b[i]);
+            a * b
+          })
+          .sum();
+      }
+
+      assert_eq!(evals[0] + evals[1], claim);
+      for e in &evals {
+        transcript.append_scalar(b"sc_round_eval", e);
+      }
+      let r_i = transcript.challenge_scalar(b"sc_challenge");
+
+      let poly = UniPoly::from_evals(&evals);
+      claim = poly.evaluate(&r_i);
+      poly_a.bound_poly_var_top(&r_i);
+      poly_b.bound_poly_var_top(&r_i);
+      r.push(r_i);
+      round_polys.push(poly);
+    }
+
+    (
+      SumcheckInstanceProof { round_polys },
+      r,
+      (poly_a[0], poly_b[0]),
+    )
+  }
+
+  /// Proves `claim = \sum_x a(x) * b(x) * c(x)`. See [`Self::prove_quad`] for
+  /// why this is generic over `T` rather than fixed to `MerlinTranscript`.
+  pub fn prove_cubic<T: SpartanTranscript>(
+    claim: &Scalar,
+    num_rounds: usize,
+    poly_a: &mut DensePolynomial,
+    poly_b: &mut DensePolynomial,
+    poly_c: &mut DensePolynomial,
+    transcript: &mut T,
+  ) -> (Self, Vec<Scalar>, (Scalar, Scalar, Scalar)) {
+    let mut claim = *claim;
+    let mut r = Vec::with_capacity(num_rounds);
+    let mut round_polys = Vec::with_capacity(num_rounds);
+
+    for _ in 0..num_rounds {
+      let n = poly_a.len() / 2;
+      let mut evals = [Scalar::zero(); 4];
+      for (t, eval) in evals.iter_mut().enumerate() {
+        let tsc = Scalar::from(t as u64);
+        *eval = (0..n)
+          .map(|i| {
+            let a = poly_a[i] + tsc * (poly_a[i + n] - poly_a[i]);
+            let b = poly_b[i] + tsc * (poly_b[i + n] - poly_b[i]);
+            let c = poly_c[i] + tsc * (poly_c[i + n] - poly_c[i]);
+            a * b * c
+          })
+          .sum();
+      }
+
+      assert_eq!(evals[0] + evals[1], claim);
+      for e in &evals {
+        transcript.append_scalar(b"sc_round_eval", e);
+      }
+      let r_i = transcript.challenge_scalar(b"sc_challenge");
+
+      let poly = UniPoly::from_evals(&evals);
+      claim = poly.evaluate(&r_i);
+      poly_a.bound_poly_var_top(&r_i);
+      poly_b.bound_poly_var_top(&r_i);
+      poly_c.bound_poly_var_top(&r_i);
+      r.push(r_i);
+      round_polys.push(poly);
+    }
+
+    (
+      SumcheckInstanceProof { round_polys },
+      r,
+      (poly_a[0], poly_b[0], poly_c[0]),
+    )
+  }
+
+  /// Checks the round-by-round structure (`g_i(0) + g_i(1) == ` previous
+  /// claim) and folds in the verifier's own challenges, returning the final
+  /// claim and the point it's claimed at. The caller is responsible for
+  /// checking that final claim against the factors' actual values at that
+  /// point (via committed-polynomial openings, or a direct recomputation
+  /// when a factor is public).
+  pub fn verify<T: SpartanTranscript>(
+    &self,
+    claim: Scalar,
+    num_rounds: usize,
+    degree_bound: usize,
+    transcript: &mut T,
+  ) -> (Scalar, Vec<Scalar>) {
+    assert_eq!(self.round_polys.len(), num_rounds);
+    let mut e = claim;
+    let mut r = Vec::with_capacity(num_rounds);
+    for poly in &self.round_polys {
+      assert_eq!(poly.evals().len(), degree_bound + 1);
+      assert_eq!(poly.eval_at_zero() + poly.eval_at_one(), e);
+      for ev in poly.evals() {
+        transcript.append_scalar(b"sc_round_eval", ev);
+      }
+      let r_i = transcript.challenge_scalar(b"sc_challenge");
+      e = poly.evaluate(&r_i);
+      r.push(r_i);
+    }
+    (e, r)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::super::math::Math;
+  use super::super::scalar::ScalarFromPrimitives;
+  use super::super::transcript::MerlinTranscript;
+
+  #[test]
+  fn check_sumcheck_cubic_round_trip() {
+    let num_rounds = 4;
+    let n = num_rounds.pow2();
+    let mut a = DensePolynomial::new((0..n).map(|i| (i as usize).to_scalar()).collect());
+    let mut b = DensePolynomial::new((0..n).map(|i| ((i + 1) as usize).to_scalar()).collect());
+    let mut c = DensePolynomial::new((0..n).map(|i| ((2 * i + 1) as usize).to_scalar()).collect());
+    let claim: Scalar = (0..n).map(|i| a[i] * b[i] * c[i]).sum();
+
+    let mut prover_transcript = MerlinTranscript::new(b"sumcheck-test");
+    let (proof, r, (a_final, b_final, c_final)) =
+      SumcheckInstanceProof::prove_cubic(&claim, num_rounds, &mut a, &mut b, &mut c, &mut prover_transcript);
+
+    let mut verifier_transcript = MerlinTranscript::new(b"sumcheck-test");
+    let (final_eval, r_verify) = proof.verify(claim, num_rounds, 3, &mut verifier_transcript);
+
+    assert_eq!(r, r_verify);
+    assert_eq!(final_eval, a_final * b_final * c_final);
+  }
+
+  // Demonstrates the payoff requests.jsonl#chunk0-6 asked for: unlike
+  // `PolyEvalProof`, sumcheck has no `LigeroTranscript` bound, so it can
+  // actually be driven end to end by the Poseidon sponge backend.
+  #[cfg(feature = "poseidon")]
+  #[test]
+  fn check_sumcheck_cubic_round_trip_poseidon() {
+    use super::super::transcript::poseidon::PoseidonTranscript;
+
+    let num_rounds = 4;
+    let n = num_rounds.pow2();
+    let mut a = DensePolynomial::new((0..n).map(|i| (i as usize).to_scalar()).collect());
+    let mut b = DensePolynomial::new((0..n).map(|i| ((i + 1) as usize).to_scalar()).collect());
+    let mut c = DensePolynomial::new((0..n).map(|i| ((2 * i + 1) as usize).to_scalar()).collect());
+    let claim: Scalar = (0..n).map(|i| a[i] * b[i] * c[i]).sum();
+
+    let mut prover_transcript = PoseidonTranscript::new(b"sumcheck-test");
+    let (proof, r, (a_final, b_final, c_final)) =
+      SumcheckInstanceProof::prove_cubic(&claim, num_rounds, &mut a, &mut b, &mut c, &mut prover_transcript);
+
+    let mut verifier_transcript = PoseidonTranscript::new(b"sumcheck-test");
+    let (final_eval, r_verify) = proof.verify(claim, num_rounds, 3, &mut verifier_transcript);
+
+    assert_eq!(r, r_verify);
+    assert_eq!(final_eval, a_final * b_final * c_final);
+  }
+}
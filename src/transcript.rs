@@ -0,0 +1,141 @@
+use super::scalar::Scalar;
+use merlin::Transcript;
+
+/// The Fiat-Shamir operations every proof in this crate drives a transcript
+/// through: absorbing prover messages and scalars, and squeezing verifier
+/// challenges. Factoring these out of a concrete hash lets a proof's
+/// Fiat-Shamir layer be swapped independently of its arithmetic; see
+/// [`MerlinTranscript`] and the `poseidon` module for the two backends this
+/// crate ships.
+pub trait SpartanTranscript {
+  fn new(label: &'static [u8]) -> Self;
+  fn append_protocol_name(&mut self, protocol_name: &'static [u8]);
+  fn append_message(&mut self, label: &'static [u8], msg: &[u8]);
+  fn append_scalar(&mut self, label: &'static [u8], scalar: &Scalar);
+  fn challenge_scalar(&mut self, label: &'static [u8]) -> Scalar;
+}
+
+/// Implemented by types that know how to serialize themselves into a
+/// transcript, generic over the backend so the same `impl` feeds either
+/// [`MerlinTranscript`] or a `poseidon::PoseidonTranscript`.
+pub trait AppendToTranscript {
+  fn append_to_transcript<T: SpartanTranscript>(&self, label: &'static [u8], transcript: &mut T);
+}
+
+/// The original backend: a merlin transcript hashed with blake3. This is the
+/// only backend [`LigeroTranscript`] is implemented for, since the vendored
+/// Ligero column-opening protocol (`ligero_pc`/`lcpc2d`) is written directly
+/// against `merlin::Transcript` and can't yet be driven by an algebraic
+/// sponge.
+pub struct MerlinTranscript(Transcript);
+
+impl SpartanTranscript for MerlinTranscript {
+  fn new(label: &'static [u8]) -> Self {
+    MerlinTranscript(Transcript::new(label))
+  }
+
+  fn append_protocol_name(&mut self, protocol_name: &'static [u8]) {
+    self.0.append_message(b"protocol-name", protocol_name);
+  }
+
+  fn append_message(&mut self, label: &'static [u8], msg: &[u8]) {
+    self.0.append_message(label, msg);
+  }
+
+  fn append_scalar(&mut self, label: &'static [u8], scalar: &Scalar) {
+    self.0.append_message(label, scalar.to_bytes().as_ref());
+  }
+
+  fn challenge_scalar(&mut self, label: &'static [u8]) -> Scalar {
+    let mut buf = [0u8; 64];
+    self.0.challenge_bytes(label, &mut buf);
+    Scalar::from_bytes_wide(&buf)
+  }
+}
+
+/// Bridges a transcript backend to the byte-oriented `merlin::Transcript`
+/// the vendored Ligero column-opening protocol expects. [`PolyEvalProof`]
+/// and [`BatchedPolyEvalProof`] (in [`super::dense_mlpoly`]) require this
+/// bound in addition to [`SpartanTranscript`] because they hand the
+/// transcript straight to `lcpc2d`'s column-opening calls. [`AppendToTranscript`]
+/// and [`super::random::RandomTape`] only need `SpartanTranscript` and so
+/// already work with a `poseidon::PoseidonTranscript`; [`super::sumcheck::SumcheckInstanceProof`]
+/// and [`super::product_tree::ProductCircuitEvalProof`] carry no such bound
+/// either and can run end to end over Poseidon too (see their `*_poseidon`
+/// tests). The memory-checking call sites in [`super::sparse_mlpoly`] reduce
+/// to [`PolyEvalProof`] openings, though, so they stay bound to
+/// `LigeroTranscript` along with it.
+///
+/// Because of this, `poseidon::PoseidonTranscript` cannot drive a
+/// `PolyEvalProof`/`BatchedPolyEvalProof` (or anything reducing to one, like
+/// [`super::sparse_mlpoly::SparseMatPolyEvalProof`]) today. Reaching full
+/// Poseidon-backed recursion for those would mean replacing the Ligero
+/// opening with a column-opening protocol that doesn't hand off to a
+/// byte-oriented `merlin::Transcript` (or porting `ligero_pc`/`lcpc2d`
+/// itself); that's a separate, larger change and explicitly out of scope
+/// here.
+///
+/// [`PolyEvalProof`]: super::dense_mlpoly::PolyEvalProof
+/// [`BatchedPolyEvalProof`]: super::dense_mlpoly::BatchedPolyEvalProof
+pub trait LigeroTranscript: SpartanTranscript {
+  fn as_merlin_mut(&mut self) -> &mut Transcript;
+}
+
+impl LigeroTranscript for MerlinTranscript {
+  fn as_merlin_mut(&mut self) -> &mut Transcript {
+    &mut self.0
+  }
+}
+
+/// A recursion-friendly transcript backend, following the Testudo variant's
+/// `PoseidonTranscript`: state is absorbed and squeezed through a Poseidon
+/// permutation over the scalar field instead of a byte hash, so an
+/// in-circuit verifier can replay the transcript with the same arithmetic
+/// constraints the rest of the proof already uses rather than unpacking
+/// blake3. Gated behind the `poseidon` feature since it pulls in the
+/// `ark-crypto-primitives` sponge implementation, mirroring how
+/// [`super::poly_commit_scheme::mlkzg`] gates its own `ark` dependency
+/// behind `mlkzg`.
+#[cfg(feature = "poseidon")]
+pub mod poseidon {
+  use super::*;
+  use ark_crypto_primitives::sponge::poseidon::PoseidonSponge;
+  use ark_crypto_primitives::sponge::CryptographicSponge;
+
+  pub struct PoseidonTranscript {
+    sponge: PoseidonSponge<super::super::scalar::ArkScalar>,
+  }
+
+  impl SpartanTranscript for PoseidonTranscript {
+    fn new(label: &'static [u8]) -> Self {
+      let mut sponge = PoseidonSponge::new(&super::super::scalar::poseidon_params());
+      sponge.absorb(&label.to_vec());
+      PoseidonTranscript { sponge }
+    }
+
+    fn append_protocol_name(&mut self, protocol_name: &'static [u8]) {
+      self.sponge.absorb(&b"protocol-name".to_vec());
+      self.sponge.absorb(&protocol_name.to_vec());
+    }
+
+    // Absorbing `label` alongside every message/scalar/challenge (as
+    // `MerlinTranscript` does by hashing it together with the payload) keeps
+    // two otherwise-identical values entering the transcript under different
+    // labels from producing the same sponge state.
+    fn append_message(&mut self, label: &'static [u8], msg: &[u8]) {
+      self.sponge.absorb(&label.to_vec());
+      self.sponge.absorb(&msg.to_vec());
+    }
+
+    fn append_scalar(&mut self, label: &'static [u8], scalar: &Scalar) {
+      self.sponge.absorb(&label.to_vec());
+      self.sponge.absorb(&super::super::scalar::scalar_to_ark(scalar));
+    }
+
+    fn challenge_scalar(&mut self, label: &'static [u8]) -> Scalar {
+      self.sponge.absorb(&label.to_vec());
+      let squeezed: Vec<super::super::scalar::ArkScalar> = self.sponge.squeeze_field_elements(1);
+      super::super::scalar::ark_to_scalar(&squeezed[0])
+    }
+  }
+}
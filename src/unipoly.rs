@@ -0,0 +1,59 @@
+use super::scalar::Scalar;
+use ff::Field;
+use serde::{Deserialize, Serialize};
+
+/// A univariate polynomial of degree `< evals.len()`, represented by its
+/// evaluations at `0, 1, ..., evals.len() - 1` rather than its coefficients.
+/// Every per-round polynomial a [`super::sumcheck::SumcheckInstanceProof`]
+/// sends has degree bounded by the number of multilinear factors being
+/// combined (at most three in this crate), so a handful of sample points is
+/// always enough to pin the polynomial down and cheaper for the prover to
+/// produce than coefficients would be.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UniPoly {
+  evals: Vec<Scalar>,
+}
+
+impl UniPoly {
+  pub fn from_evals(evals: &[Scalar]) -> Self {
+    UniPoly {
+      evals: evals.to_vec(),
+    }
+  }
+
+  pub fn degree(&self) -> usize {
+    self.evals.len() - 1
+  }
+
+  pub fn evals(&self) -> &[Scalar] {
+    &self.evals
+  }
+
+  pub fn eval_at_zero(&self) -> Scalar {
+    self.evals[0]
+  }
+
+  pub fn eval_at_one(&self) -> Scalar {
+    self.evals[1]
+  }
+
+  /// Evaluates at an arbitrary point via Lagrange interpolation over the
+  /// sample points `0..evals.len()`.
+  pub fn evaluate(&self, r: &Scalar) -> Scalar {
+    let n = self.evals.len();
+    let mut result = Scalar::zero();
+    for i in 0..n {
+      let mut num = Scalar::one();
+      let mut den = Scalar::one();
+      for j in 0..n {
+        if i == j {
+          continue;
+        }
+        num *= *r - Scalar::from(j as u64);
+        den *= Scalar::from(i as u64) - Scalar::from(j as u64);
+      }
+      result += self.evals[i] * num * den.invert().unwrap();
+    }
+    result
+  }
+}